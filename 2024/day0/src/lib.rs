@@ -0,0 +1,49 @@
+use common::{ParseError, Rng, Solution};
+
+fn process(input: &str) -> Result<String, ParseError> {
+    Ok(input.trim().to_owned())
+}
+
+fn part1(input: &str) -> impl ToString {
+    input.to_owned()
+}
+
+fn part2(input: &str) -> impl ToString {
+    input.chars().rev().collect::<String>()
+}
+
+/// `size` lowercase letters.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut letters: String = (0..size)
+        .map(|_| (b'a' + rng.below(26) as u8) as char)
+        .collect();
+    letters.push('\n');
+
+    letters
+}
+
+pub struct Day0;
+
+impl Solution for Day0 {
+    type Input = String;
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 0;
+
+    fn process(input: &str) -> Result<Self::Input, ParseError> {
+        process(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl ToString {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl ToString {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+common::example_tests!(Day0);
@@ -0,0 +1,89 @@
+use common::{Counter, ParseError, Rng, Scanner, Solution};
+use std::iter::zip;
+
+fn process(input: &str) -> Result<(Vec<u32>, Vec<u32>), ParseError> {
+    let mut left: Vec<u32> = vec![];
+    let mut right: Vec<u32> = vec![];
+
+    for mut line in Scanner::within(input, input.trim()).lines() {
+        left.push(line.next_uint()?);
+        right.push(line.next_uint()?);
+        line.expect_end()?;
+    }
+
+    Ok((left, right))
+}
+
+fn part1(input: &(Vec<u32>, Vec<u32>)) -> impl ToString {
+    let (mut left, mut right) = input.clone();
+    left.sort();
+    right.sort();
+
+    zip(left, right).map(|(a, b)| a.abs_diff(b)).sum::<u32>()
+}
+
+fn part2(input: &(Vec<u32>, Vec<u32>)) -> impl ToString {
+    let (left, right) = input;
+
+    let right_counts: Counter<u32, u32> = right.iter().copied().collect();
+
+    left.iter()
+        .map(|curr| curr * right_counts.get(curr))
+        .sum::<u32>()
+}
+
+/// `size` pairs of five-digit location IDs, with some left IDs repeated on the right.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let left: Vec<i64> = (0..size).map(|_| rng.range(10000..=99999)).collect();
+
+    left.iter()
+        .map(|id| {
+            let right = if rng.chance(0.2) {
+                *rng.choose(&left)
+            } else {
+                rng.range(10000..=99999)
+            };
+
+            format!("{}   {}\n", id, right)
+        })
+        .collect()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = (Vec<u32>, Vec<u32>);
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 1;
+
+    fn process(input: &str) -> Result<Self::Input, ParseError> {
+        process(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl ToString {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl ToString {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+common::example_tests!(Day1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_error() {
+        let err = process("3   4\n4   x\n").unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 5));
+        assert_eq!(err.text, "x");
+    }
+}
@@ -0,0 +1,91 @@
+use common::{graph, parallel, Dir4, Grid, Image, ParseError, Part, Point, Rgb, Rng, Solution};
+
+fn process(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(input, |c| Some(c.to_digit(10).unwrap_or(10) as u8))
+}
+
+/// Where a trail can go from `pos`: one step higher, up to the top at 9.
+fn uphill(map: &Grid<u8>, pos: Point) -> impl Iterator<Item = Point> + '_ {
+    map.neighbors4(pos)
+        .filter(move |&next| map[pos] < 9 && map[next] == map[pos] + 1)
+}
+
+fn trailheads(map: &Grid<u8>) -> Vec<Point> {
+    map.positions(|&height| height == 0).collect()
+}
+
+fn part1(input: &Grid<u8>) -> impl ToString {
+    parallel::sum(&trailheads(input), |&start| {
+        graph::bfs([start], |pos| uphill(input, pos))
+            .nodes()
+            .filter(|&pos| input[pos] == 9)
+            .count()
+    })
+}
+
+/// Every trail is as short as it can be, so the trails to a top are its shortest paths.
+fn part2(input: &Grid<u8>) -> impl ToString {
+    parallel::sum(&trailheads(input), |&start| {
+        graph::all_shortest_paths([start], |pos| uphill(input, pos).map(|next| (next, 1)))
+            .path_counts()
+            .into_iter()
+            .filter(|&(pos, _)| input[pos] == 9)
+            .map(|(_, count)| count)
+            .sum::<u64>()
+    })
+}
+
+/// A `size`×`size` map of random heights with `size` climbing trails painted over it.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut map = Grid::filled(size, size, 0u8);
+    for pos in map.coords().collect::<Vec<_>>() {
+        map[pos] = rng.below(10) as u8;
+    }
+
+    for _ in 0..size {
+        let mut pos = Point::from_row_col(rng.below(size), rng.below(size));
+        for height in 0..10 {
+            map[pos] = height;
+            pos = map.offset(pos, *rng.choose(&Dir4::ALL)).unwrap_or(pos);
+        }
+    }
+
+    map.to_string()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<u8>;
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 10;
+
+    fn process(input: &str) -> Result<Self::Input, ParseError> {
+        process(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl ToString {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl ToString {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+
+    /// Heights from black (0) to white (9), impassable tiles in red.
+    fn image(input: &Self::Input, _part: Part) -> Option<Image> {
+        Some(Image::from_grid(input, |&height| match height {
+            0..=9 => {
+                let level = height * 28 + 3;
+                Rgb(level, level, level)
+            }
+            _ => Rgb(120, 0, 0),
+        }))
+    }
+}
+
+common::example_tests!(Day10);
@@ -0,0 +1,94 @@
+use common::{math, parse, Counter, ParseError, Rng, Solution};
+
+#[cfg(test)]
+mod reference;
+
+fn process(input: &str) -> Result<Vec<usize>, ParseError> {
+    input
+        .trim()
+        .split_ascii_whitespace()
+        .map(|raw| parse::number(input, raw))
+        .collect()
+}
+
+fn blink(stone: usize) -> Vec<usize> {
+    if stone == 0 {
+        return vec![1];
+    }
+
+    let num_digits = math::digit_count(stone, 10);
+    if num_digits.is_multiple_of(2) {
+        let (left, right) = math::split_digits(stone, num_digits / 2, 10);
+        return vec![left, right];
+    }
+
+    vec![stone * 2024]
+}
+
+#[allow(clippy::ptr_arg)]
+fn count_after_blinks(initial_stones: &Vec<usize>, num_blinks: usize) -> usize {
+    let mut stone_counts: Counter<usize, usize> = initial_stones.iter().copied().collect();
+
+    for _ in 0..num_blinks {
+        stone_counts = stone_counts.expand(|&stone| blink(stone).into_iter().map(|new| (new, 1)));
+    }
+
+    stone_counts.total()
+}
+
+fn part1(input: &Vec<usize>) -> impl ToString {
+    count_after_blinks(input, 25)
+}
+
+fn part2(input: &Vec<usize>) -> impl ToString {
+    count_after_blinks(input, 75)
+}
+
+/// `size` stones engraved with numbers of up to seven digits.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let stones: Vec<String> = (0..size)
+        .map(|_| {
+            let digits = rng.range(1..=7) as u32;
+            rng.range(0..=10i64.pow(digits) - 1).to_string()
+        })
+        .collect();
+
+    stones.join(" ") + "\n"
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<usize>;
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 11;
+
+    fn process(input: &str) -> Result<Self::Input, ParseError> {
+        process(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl ToString {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl ToString {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+common::example_tests!(Day11);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{differential, Part};
+
+    #[test]
+    fn test_matches_reference() {
+        differential::compare::<Day11, _>(Part::One, |input| reference::part1(input), 50, 3);
+    }
+}
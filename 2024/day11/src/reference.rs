@@ -0,0 +1,24 @@
+//! A naive solution that keeps every stone in a list.
+
+pub fn part1(input: &[usize]) -> usize {
+    let mut stones = input.to_vec();
+
+    for _ in 0..25 {
+        stones = stones
+            .into_iter()
+            .flat_map(|stone| {
+                let digits = stone.to_string();
+                if stone == 0 {
+                    vec![1]
+                } else if digits.len() % 2 == 0 {
+                    let (left, right) = digits.split_at(digits.len() / 2);
+                    vec![left.parse().unwrap(), right.parse().unwrap()]
+                } else {
+                    vec![stone * 2024]
+                }
+            })
+            .collect();
+    }
+
+    stones.len()
+}
@@ -0,0 +1,152 @@
+use common::{graph, parallel, Dir4, Grid, Image, ParseError, Part, Point, Rgb, Rng, Solution};
+use std::collections::HashSet;
+
+#[cfg(test)]
+mod reference;
+
+const CORNER_PAIRS: [[Dir4; 2]; 4] = [
+    [Dir4::Up, Dir4::Left],
+    [Dir4::Up, Dir4::Right],
+    [Dir4::Down, Dir4::Left],
+    [Dir4::Down, Dir4::Right],
+];
+
+/// How many corners of its region meet at `pos`. A region has as many sides as corners.
+fn corners(map: &Grid<char>, pos: Point) -> usize {
+    let current = map[pos];
+
+    CORNER_PAIRS
+        .iter()
+        .filter(
+            |dirs| match (map.offset(pos, dirs[0]), map.offset(pos, dirs[1])) {
+                (None, None) => true,
+                (None, Some(other)) => map[other] != current,
+                (Some(other), None) => map[other] != current,
+                (Some(first_other), Some(second_other)) => {
+                    let others = (map[first_other], map[second_other]);
+
+                    (others.0 != current && others.1 != current)
+                        || (others.0 == current
+                            && others.1 == current
+                            && map[map.offset(first_other, dirs[1]).unwrap()] != current)
+                }
+            },
+        )
+        .count()
+}
+
+/// Every region, as the plots in it.
+fn regions(map: &Grid<char>) -> Vec<Vec<Point>> {
+    let mut seen = HashSet::new();
+    let mut regions = vec![];
+
+    for (pos, &plant) in map.iter() {
+        if seen.contains(&pos) {
+            continue;
+        }
+
+        let same_plant = |pos| map.neighbors4(pos).filter(move |&next| map[next] == plant);
+        let region: Vec<Point> = graph::bfs([pos], same_plant).nodes().collect();
+        seen.extend(region.iter().copied());
+        regions.push(region);
+    }
+
+    regions
+}
+
+/// The area, perimeter and number of sides of each region.
+fn discover_all_plots(map: &Grid<char>) -> Vec<(usize, usize, usize)> {
+    parallel::map(&regions(map), |region| {
+        let plant = map[region[0]];
+        let fences = region
+            .iter()
+            .map(|&pos| {
+                4 - map
+                    .neighbors4(pos)
+                    .filter(|&next| map[next] == plant)
+                    .count()
+            })
+            .sum();
+        let sides = region.iter().map(|&pos| corners(map, pos)).sum();
+
+        (region.len(), fences, sides)
+    })
+}
+
+fn process(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input, Some)
+}
+
+fn part1(input: &Grid<char>) -> impl ToString {
+    discover_all_plots(input)
+        .iter()
+        .fold(0, |acc, curr| acc + curr.0 * curr.1)
+}
+
+fn part2(input: &Grid<char>) -> impl ToString {
+    discover_all_plots(input)
+        .iter()
+        .fold(0, |acc, curr| acc + curr.0 * curr.2)
+}
+
+/// A `size`×`size` garden where most plots continue the region above or to their left.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut map = Grid::filled(size, size, 'A');
+
+    for pos in map.coords().collect::<Vec<_>>() {
+        let above = map.offset(pos, Dir4::Up).filter(|_| rng.chance(0.45));
+        let left = map.offset(pos, Dir4::Left).filter(|_| rng.chance(0.7));
+
+        map[pos] = match above.or(left) {
+            Some(neighbor) => map[neighbor],
+            None => (b'A' + rng.below(26) as u8) as char,
+        };
+    }
+
+    map.to_string()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Grid<char>;
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 12;
+
+    fn process(input: &str) -> Result<Self::Input, ParseError> {
+        process(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl ToString {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl ToString {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+
+    /// Regions coloured by plant, with hues spread so neighbouring letters stand apart.
+    fn image(input: &Self::Input, _part: Part) -> Option<Image> {
+        Some(Image::from_grid(input, |&plant| {
+            Rgb::hue(plant as u32 as f64 * 0.618_034)
+        }))
+    }
+}
+
+common::example_tests!(Day12);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{differential, Part};
+
+    #[test]
+    fn test_matches_reference() {
+        differential::compare::<Day12, _>(Part::One, reference::part1, 200, 12);
+        differential::compare::<Day12, _>(Part::Two, reference::part2, 200, 12);
+    }
+}
@@ -0,0 +1,69 @@
+//! Naive solutions that flood fill each region and walk its edges.
+
+use common::{Dir4, Grid, Point};
+use std::collections::HashSet;
+
+fn regions(map: &Grid<char>) -> Vec<HashSet<Point>> {
+    let mut seen = HashSet::new();
+    let mut regions = vec![];
+
+    for (start, &plant) in map.iter() {
+        if seen.contains(&start) {
+            continue;
+        }
+
+        let mut region = HashSet::from([start]);
+        let mut stack = vec![start];
+        while let Some(pos) = stack.pop() {
+            for neighbor in map.neighbors4(pos) {
+                if map[neighbor] == plant && region.insert(neighbor) {
+                    stack.push(neighbor);
+                }
+            }
+        }
+
+        seen.extend(region.iter().copied());
+        regions.push(region);
+    }
+
+    regions
+}
+
+/// Every side of a plot that faces out of its region.
+fn fences(region: &HashSet<Point>) -> HashSet<(Point, Dir4)> {
+    region
+        .iter()
+        .flat_map(|&pos| Dir4::ALL.map(|dir| (pos, dir)))
+        .filter(|&(pos, dir)| {
+            pos.checked_add(dir)
+                .is_none_or(|neighbor| !region.contains(&neighbor))
+        })
+        .collect()
+}
+
+pub fn part1(map: &Grid<char>) -> usize {
+    regions(map)
+        .iter()
+        .map(|region| region.len() * fences(region).len())
+        .sum()
+}
+
+pub fn part2(map: &Grid<char>) -> usize {
+    regions(map)
+        .iter()
+        .map(|region| {
+            let fences = fences(region);
+
+            // A side starts at every fence whose neighbour along the side has no matching fence.
+            let sides = fences
+                .iter()
+                .filter(|&&(pos, dir)| {
+                    pos.checked_add(dir.turn_left())
+                        .is_none_or(|previous| !fences.contains(&(previous, dir)))
+                })
+                .count();
+
+            region.len() * sides
+        })
+        .sum()
+}
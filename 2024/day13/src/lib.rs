@@ -0,0 +1,219 @@
+use common::grammar::{preceded, sections, terminated, uint};
+use common::{math, ParseError, Parser, Rng, Solution};
+
+#[cfg(test)]
+mod reference;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Machine {
+    a: (i64, i64),
+    b: (i64, i64),
+    prize: (i64, i64),
+}
+
+impl Machine {
+    fn with_adjusted_prize(&self, distance: i64) -> Self {
+        Self {
+            a: self.a,
+            b: self.b,
+            prize: (self.prize.0 + distance, self.prize.1 + distance),
+        }
+    }
+
+    fn solve(&self, max_presses: i64) -> Option<(i64, i64)> {
+        let det = self.a.0 * self.b.1 - self.a.1 * self.b.0;
+        if det == 0 {
+            return self.solve_collinear(max_presses);
+        }
+
+        // Cramer's rule; presses that don't come out whole can't win.
+        let exact = |n: i64| (n % det == 0).then_some(n / det);
+        let a = exact(self.prize.0 * self.b.1 - self.prize.1 * self.b.0)?;
+        let b = exact(self.a.0 * self.prize.1 - self.a.1 * self.prize.0)?;
+
+        let in_bounds = |presses| (0..=max_presses).contains(&presses);
+        (in_bounds(a) && in_bounds(b)).then_some((a, b))
+    }
+
+    /// With both buttons moving along the same line there can be many ways to win. Solutions
+    /// repeat every `period` presses of A and the cost changes linearly along them, so the
+    /// cheapest uses either the fewest or the most A presses in bounds. Offsets are not negative.
+    fn solve_collinear(&self, max_presses: i64) -> Option<(i64, i64)> {
+        let in_bounds = |presses: &i64| (0..=max_presses).contains(presses);
+        match (self.a, self.b) {
+            ((0, 0), (0, 0)) => return (self.prize == (0, 0)).then_some((0, 0)),
+            ((0, 0), b) => return presses(b, self.prize).filter(in_bounds).map(|b| (0, b)),
+            (a, (0, 0)) => return presses(a, self.prize).filter(in_bounds).map(|a| (a, 0)),
+            _ => {}
+        }
+        if self.a.0 * self.prize.1 != self.a.1 * self.prize.0 {
+            return None;
+        }
+
+        // Parallel buttons either both move along X or neither does.
+        let axis = |offsets: (i64, i64)| if self.a.0 != 0 { offsets.0 } else { offsets.1 };
+        let (a_step, b_step, target) = (axis(self.a), axis(self.b), axis(self.prize));
+        let (first, period) = math::linear_congruence(a_step, target, b_step)?;
+
+        let min_a = ((target - max_presses.saturating_mul(b_step)).max(0) + a_step - 1) / a_step;
+        let max_a = max_presses.min(target / a_step);
+        let fewest = first + ((min_a - first).max(0) + period - 1) / period * period;
+        if fewest > max_a {
+            return None;
+        }
+        let most = first + (max_a - first) / period * period;
+
+        let a = if a_step > 3 * b_step { most } else { fewest };
+        Some((a, (target - a * a_step) / b_step))
+    }
+}
+
+/// How many presses of a button moving `step`, which is not (0, 0), land exactly on `prize`.
+fn presses(step: (i64, i64), prize: (i64, i64)) -> Option<i64> {
+    let n = if step.0 != 0 {
+        prize.0 / step.0
+    } else {
+        prize.1 / step.1
+    };
+
+    (step.0 * n == prize.0 && step.1 * n == prize.1).then_some(n)
+}
+
+fn process(input: &str) -> Result<Vec<Machine>, ParseError> {
+    let offsets = |x, y| (preceded(x, uint()), preceded(", ", preceded(y, uint())));
+    let machine = (
+        terminated(offsets("Button A: X+", "Y+"), "\n"),
+        terminated(offsets("Button B: X+", "Y+"), "\n"),
+        offsets("Prize: X=", "Y="),
+    )
+        .map(|(a, b, prize)| Machine { a, b, prize });
+
+    sections(machine).parse_all(input)
+}
+
+#[allow(clippy::ptr_arg)]
+fn part1(input: &Vec<Machine>) -> impl ToString {
+    input.iter().fold(0, |acc, &machine| {
+        if let Some(solution) = machine.solve(100) {
+            acc + solution.0 * 3 + solution.1
+        } else {
+            acc
+        }
+    })
+}
+
+#[allow(clippy::ptr_arg)]
+fn part2(input: &Vec<Machine>) -> impl ToString {
+    input.iter().fold(0, |acc, &machine| {
+        if let Some(solution) = machine.with_adjusted_prize(10000000000000).solve(i64::MAX) {
+            acc + solution.0 * 3 + solution.1
+        } else {
+            acc
+        }
+    })
+}
+
+/// `size` machines whose buttons move 10 to 99 each way, one in ten with collinear buttons, and
+/// whose prizes are reachable about half the time.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let machines: Vec<String> = (0..size)
+        .map(|_| {
+            let (a, b) = if rng.chance(0.1) {
+                let base = (rng.range(10..=24), rng.range(10..=24));
+                let (a_factor, b_factor) = (rng.range(1..=4), rng.range(1..=3));
+
+                (
+                    (base.0 * a_factor, base.1 * a_factor),
+                    (base.0 * b_factor, base.1 * b_factor),
+                )
+            } else {
+                (
+                    (rng.range(10..=99), rng.range(10..=99)),
+                    (rng.range(10..=99), rng.range(10..=99)),
+                )
+            };
+
+            let (a_presses, b_presses) = (rng.range(0..=100), rng.range(0..=100));
+            let mut prize = (
+                a.0 * a_presses + b.0 * b_presses,
+                a.1 * a_presses + b.1 * b_presses,
+            );
+            if rng.chance(0.5) {
+                prize.0 += rng.range(1..=5);
+            }
+
+            format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                a.0, a.1, b.0, b.1, prize.0, prize.1
+            )
+        })
+        .collect();
+
+    machines.join("\n")
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Machine>;
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 13;
+
+    fn process(input: &str) -> Result<Self::Input, ParseError> {
+        process(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl ToString {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl ToString {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+common::example_tests!(Day13);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{differential, Part};
+
+    #[test]
+    fn test_matches_reference() {
+        differential::compare::<Day13, _>(Part::One, |input| reference::part1(input), 200, 10);
+    }
+
+    #[test]
+    fn test_process_error() {
+        let err = process(
+            "
+                Button A: X+94, Y+34\n\
+                Button B: X+22, Y+67\n\
+                Prize: X=8400\n\
+            ",
+        )
+        .unwrap_err();
+
+        assert_eq!(err.line, 4);
+        assert_eq!(err.message, "expected \", \"");
+    }
+
+    #[test]
+    fn test_zero_offsets() {
+        let solve = |a, b, prize| Machine { a, b, prize }.solve(100);
+
+        assert_eq!(solve((0, 5), (0, 2), (0, 11)), Some((1, 3)));
+        assert_eq!(solve((0, 5), (0, 2), (1, 11)), None);
+        assert_eq!(solve((0, 0), (2, 3), (4, 6)), Some((0, 2)));
+        assert_eq!(solve((0, 0), (2, 3), (4, 7)), None);
+        assert_eq!(solve((1, 1), (0, 0), (5, 5)), Some((5, 0)));
+        assert_eq!(solve((1, 1), (0, 0), (500, 500)), None);
+        assert_eq!(solve((0, 0), (0, 0), (0, 0)), Some((0, 0)));
+        assert_eq!(solve((0, 0), (0, 0), (1, 0)), None);
+    }
+}
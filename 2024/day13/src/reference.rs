@@ -0,0 +1,21 @@
+//! A naive solution that tries every press count up to 100.
+
+use crate::Machine;
+
+pub fn part1(input: &[Machine]) -> i64 {
+    input
+        .iter()
+        .filter_map(|machine| {
+            (0..=100)
+                .flat_map(|a| (0..=100).map(move |b| (a, b)))
+                .filter(|&(a, b)| {
+                    (
+                        a * machine.a.0 + b * machine.b.0,
+                        a * machine.a.1 + b * machine.b.1,
+                    ) == machine.prize
+                })
+                .map(|(a, b)| 3 * a + b)
+                .min()
+        })
+        .sum()
+}
@@ -0,0 +1,276 @@
+use common::grammar::{int, preceded, separated, uint};
+use common::{
+    math, Cell, Color, Frame, Frames, Grid, Image, ParseError, Parser, Part, Point, Rgb, Rng,
+    Solution, Vec2,
+};
+
+const SIZE: Point = Point::from_xy(101, 103);
+
+#[derive(Debug, Clone, Copy)]
+pub struct Robot {
+    pos: Point,
+    vel: Vec2,
+}
+
+impl Robot {
+    fn after_seconds(&self, seconds: usize, size: Point) -> Self {
+        let wrap = |pos: usize, vel: isize, len: usize| {
+            (pos as isize + vel * seconds as isize).rem_euclid(len as isize) as usize
+        };
+
+        Self {
+            pos: Point::from_xy(
+                wrap(self.pos.x(), self.vel.x(), size.x()),
+                wrap(self.pos.y(), self.vel.y(), size.y()),
+            ),
+            vel: self.vel,
+        }
+    }
+}
+
+/// The robots and the floor they walk, which is smaller in the examples.
+#[derive(Debug, Clone)]
+pub struct Floor {
+    robots: Vec<Robot>,
+    size: Point,
+}
+
+fn process(input: &str) -> Result<Floor, ParseError> {
+    let robot = (
+        preceded("p=", uint()),
+        preceded(",", uint()),
+        preceded(" v=", int()),
+        preceded(",", int()),
+    )
+        .map(|(x, y, dx, dy)| Robot {
+            pos: Point::from_xy(x, y),
+            vel: Vec2::from_xy(dx, dy),
+        });
+
+    Ok(Floor {
+        robots: separated(robot, "\n").parse_all(input)?,
+        size: SIZE,
+    })
+}
+
+#[allow(clippy::ptr_arg)]
+fn part1(input: &Vec<Robot>, size: Point) -> impl ToString {
+    input
+        .iter()
+        .map(|robot| robot.after_seconds(100, size))
+        .fold([0, 0, 0, 0], |mut acc, robot| {
+            match (
+                robot.pos.x().cmp(&(size.x() / 2)),
+                robot.pos.y().cmp(&(size.y() / 2)),
+            ) {
+                (std::cmp::Ordering::Less, std::cmp::Ordering::Less) => acc[0] += 1,
+                (std::cmp::Ordering::Less, std::cmp::Ordering::Greater) => acc[1] += 1,
+                (std::cmp::Ordering::Greater, std::cmp::Ordering::Less) => acc[2] += 1,
+                (std::cmp::Ordering::Greater, std::cmp::Ordering::Greater) => acc[3] += 1,
+                _ => (),
+            };
+            acc
+        })
+        .iter()
+        .product::<usize>()
+}
+
+#[allow(clippy::ptr_arg)]
+fn part2(input: &Vec<Robot>, size: Point) -> impl ToString {
+    match find_tree(input, size) {
+        Some(second) => second.to_string(),
+        None => "no tree".to_string(),
+    }
+}
+
+/// The second the robots draw the tree. Columns come round every `size.x()` seconds and rows
+/// every `size.y()`, so the picture is where the seconds the columns and the rows bunch up most
+/// meet. Should the sizes not be coprime, or that not give ten robots in a row, the first second
+/// that does is searched for. Every position repeats within `size.x() * size.y()` seconds, so
+/// `None` if none does by then.
+fn find_tree(input: &[Robot], size: Point) -> Option<usize> {
+    let (columns, rows) = (
+        tightest(input, size, size.x(), Point::x),
+        tightest(input, size, size.y(), Point::y),
+    );
+    let second = math::checked_crt(&[
+        (columns as i64, size.x() as i64),
+        (rows as i64, size.y() as i64),
+    ])
+    .map(|(second, _)| second as usize);
+
+    second
+        .filter(|&second| has_row(input, second, size))
+        .or_else(|| (0..size.x() * size.y()).find(|&second| has_row(input, second, size)))
+}
+
+/// The second in `0..period` at which the robots' `coord`s spread out least.
+fn tightest(input: &[Robot], size: Point, period: usize, coord: fn(&Point) -> usize) -> usize {
+    (0..period)
+        .min_by_key(|&second| {
+            let (n, sum, squares) = input
+                .iter()
+                .map(|robot| coord(&robot.after_seconds(second, size).pos) as u64)
+                .fold((0, 0, 0), |(n, sum, squares), c| {
+                    (n + 1, sum + c, squares + c * c)
+                });
+
+            n * squares - sum * sum
+        })
+        .unwrap()
+}
+
+/// Whether ten robots stand in a row after `seconds`.
+fn has_row(input: &[Robot], seconds: usize, size: Point) -> bool {
+    floor(input, seconds, size)
+        .iter_rows()
+        .any(|row| row.windows(10).any(|run| run.iter().all(|&on| on)))
+}
+
+/// Which tiles have a robot on them after `seconds`.
+fn floor(input: &[Robot], seconds: usize, size: Point) -> Grid<bool> {
+    let mut floor = Grid::filled(size.y(), size.x(), false);
+    for robot in input {
+        floor[robot.after_seconds(seconds, size).pos] = true;
+    }
+
+    floor
+}
+
+/// Every second up to and including the picture part 2 looks for; `None` if there is none.
+fn frames(input: Vec<Robot>, size: Point) -> Option<Frames> {
+    let tree = find_tree(&input, size)?;
+
+    Some(Box::new((0..=tree).map(move |second| {
+        let mut counts = Grid::filled(size.y(), size.x(), 0u32);
+        for robot in &input {
+            counts[robot.after_seconds(second, size).pos] += 1;
+        }
+
+        let cells = counts.map(|&count| match count {
+            0 => Cell::new('.', Color::Gray),
+            1..=9 => Cell::new(char::from_digit(count, 10).unwrap(), Color::Green),
+            _ => Cell::new('+', Color::Green),
+        });
+        let caption = if second == tree {
+            format!("second {} (the tree)", second)
+        } else {
+            format!("second {}", second)
+        };
+
+        Frame::new(cells, caption)
+    })))
+}
+
+/// `size` robots on the full-size floor (at least 12). Twelve of them line up in a row at some
+/// second, so part 2 always has a picture to find.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let seconds = rng.below(SIZE.x() * SIZE.y()) as i64;
+    let (row, first_col) = (rng.below(SIZE.y()), rng.below(SIZE.x() - 12));
+
+    (0..size.max(12))
+        .map(|i| {
+            let vel = (rng.range(-100..=100), rng.range(-100..=100));
+            let pos = if i < 12 {
+                let wrap = |end: usize, vel: i64, len: usize| {
+                    (end as i64 - vel * seconds).rem_euclid(len as i64)
+                };
+
+                (
+                    wrap(first_col + i, vel.0, SIZE.x()),
+                    wrap(row, vel.1, SIZE.y()),
+                )
+            } else {
+                (rng.below(SIZE.x()) as i64, rng.below(SIZE.y()) as i64)
+            };
+
+            format!("p={},{} v={},{}\n", pos.0, pos.1, vel.0, vel.1)
+        })
+        .collect()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Floor;
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 14;
+
+    fn process(input: &str) -> Result<Self::Input, ParseError> {
+        process(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl ToString {
+        part1(&input.robots, input.size)
+    }
+
+    fn part2(input: &Self::Input) -> impl ToString {
+        part2(&input.robots, input.size)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+
+    fn frames(input: Self::Input, _part: Part) -> Option<Frames> {
+        frames(input.robots, input.size)
+    }
+
+    /// The floor when the picture appears, if it does.
+    fn image(input: &Self::Input, _part: Part) -> Option<Image> {
+        let second = find_tree(&input.robots, input.size)?;
+        let floor = floor(&input.robots, second, input.size);
+
+        Some(Image::from_grid(&floor, |&on| {
+            if on {
+                Rgb(13, 188, 121)
+            } else {
+                Rgb::BLACK
+            }
+        }))
+    }
+
+    /// `size = WIDTH,HEIGHT` for the examples' smaller floor.
+    fn set_param(input: &mut Self::Input, name: &str, value: &str) -> Result<(), String> {
+        if name != "size" {
+            return Err(format!("unknown parameter {:?}", name));
+        }
+
+        let (x, y) = value.split_once(',').ok_or("expected WIDTH,HEIGHT")?;
+        let length = |n: &str| {
+            n.trim()
+                .parse()
+                .map_err(|_| format!("bad size {:?}", value))
+        };
+        input.size = Point::from_xy(length(x)?, length(y)?);
+
+        Ok(())
+    }
+}
+
+common::example_tests!(Day14);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_tree() {
+        for seed in 0..2 {
+            let input = process(&generate(&mut Rng::new(seed), 500)).unwrap().robots;
+            let first = (0..).find(|&second| has_row(&input, second, SIZE));
+
+            assert_eq!(find_tree(&input, SIZE), first);
+        }
+    }
+
+    #[test]
+    fn test_no_tree() {
+        let mut input = process("p=0,0 v=1,1\np=3,2 v=-2,1\n").unwrap();
+        input.size = Point::from_xy(10, 4);
+
+        assert_eq!(find_tree(&input.robots, input.size), None);
+        assert_eq!(part2(&input.robots, input.size).to_string(), "no tree");
+        assert!(frames(input.robots, input.size).is_none());
+    }
+}
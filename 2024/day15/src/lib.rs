@@ -0,0 +1,302 @@
+use common::{animate, Color, Dir4, Frame, Frames, Grid, ParseError, Part, Point, Rng, Solution};
+use std::collections::HashSet;
+use std::iter;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Cell {
+    Box,
+    LeftBox,
+    RightBox,
+    Empty,
+    Wall,
+    Robot,
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    cells: Grid<Cell>,
+    robot: Point,
+}
+
+impl Map {
+    fn doubled(&self) -> Self {
+        let cells = self
+            .cells
+            .iter()
+            .flat_map(|(_, cell)| match cell {
+                Cell::Box => [Cell::LeftBox, Cell::RightBox],
+                Cell::Empty => [Cell::Empty, Cell::Empty],
+                Cell::Wall => [Cell::Wall, Cell::Wall],
+                Cell::Robot => [Cell::Robot, Cell::Empty],
+                Cell::LeftBox => unreachable!(),
+                Cell::RightBox => unreachable!(),
+            })
+            .collect();
+
+        Self {
+            cells: Grid::new(self.cells.rows(), self.cells.cols() * 2, cells),
+            robot: Point::from_row_col(self.robot.row, self.robot.col * 2),
+        }
+    }
+
+    fn get_shifts(&self, coord: Point, direction: Dir4) -> Option<Vec<[Point; 2]>> {
+        let sources = match self.cells[coord] {
+            Cell::LeftBox if direction.is_vertical() => {
+                vec![coord, coord.checked_add(Dir4::Right)?]
+            }
+            Cell::RightBox if direction.is_vertical() => {
+                vec![coord, coord.checked_add(Dir4::Left)?]
+            }
+            Cell::Box | Cell::LeftBox | Cell::RightBox | Cell::Robot => vec![coord],
+            Cell::Empty => vec![],
+            Cell::Wall => return None,
+        };
+
+        sources
+            .into_iter()
+            .map(|from| Some([from, self.cells.offset(from, direction)?]))
+            .collect::<Option<Vec<_>>>()
+            .and_then(|coord_pairs| {
+                coord_pairs
+                    .iter()
+                    .map(|coord_pair| (coord_pair, self.get_shifts(coord_pair[1], direction)))
+                    .try_fold(
+                        vec![],
+                        |mut acc, (&coord_pair, maybe_to_move)| match maybe_to_move {
+                            Some(mut to_move) => {
+                                to_move.push(coord_pair);
+                                acc.extend(to_move);
+
+                                Some(acc)
+                            }
+                            _ => None,
+                        },
+                    )
+            })
+    }
+}
+
+fn process(input: &str) -> Result<(Map, Vec<Dir4>), ParseError> {
+    let (cells_raw, directions_raw) = input.trim().split_once("\n\n").ok_or_else(|| {
+        ParseError::at_end(input, "expected a blank line between the map and the moves")
+    })?;
+
+    let cells = Grid::parse_section(input, cells_raw, |c| match c {
+        '.' => Some(Cell::Empty),
+        'O' => Some(Cell::Box),
+        '#' => Some(Cell::Wall),
+        '@' => Some(Cell::Robot),
+        _ => None,
+    })?;
+
+    let robots: Vec<Point> = cells.positions(|&cell| cell == Cell::Robot).collect();
+    let robot = match robots[..] {
+        [robot] => robot,
+        [] => return Err(ParseError::at_end(input, "no robot '@' in the map")),
+        _ => {
+            let (second, _) = cells_raw.match_indices('@').nth(1).unwrap();
+            return Err(ParseError::at(
+                input,
+                &cells_raw[second..second + 1],
+                "more than one robot '@' in the map",
+            ));
+        }
+    };
+
+    let directions = directions_raw
+        .char_indices()
+        .filter(|(_, c)| !c.is_ascii_whitespace())
+        .map(|(i, c)| {
+            Dir4::from_glyph(c).ok_or_else(|| {
+                ParseError::at(input, &directions_raw[i..i + c.len_utf8()], "unknown move")
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((Map { cells, robot }, directions))
+}
+
+fn move_robot(map: &mut Map, direction: Dir4) {
+    let robot = map.robot;
+    if let Some(to_move) = map.get_shifts(robot, direction) {
+        let mut seen = HashSet::new();
+        to_move.into_iter().for_each(|coord_pair| {
+            if !seen.insert(coord_pair) {
+                return;
+            }
+
+            map.cells[coord_pair[1]] = map.cells[coord_pair[0]];
+            map.cells[coord_pair[0]] = Cell::Empty;
+        });
+        map.robot = map.cells.offset(robot, direction).unwrap();
+    }
+}
+
+fn run_robot(mut map: Map, directions: &[Dir4]) -> Map {
+    directions
+        .iter()
+        .for_each(|&direction| move_robot(&mut map, direction));
+
+    map
+}
+
+fn draw(map: &Map) -> Grid<animate::Cell> {
+    map.cells.map(|cell| match cell {
+        Cell::Box => animate::Cell::new('O', Color::Yellow),
+        Cell::LeftBox => animate::Cell::new('[', Color::Yellow),
+        Cell::RightBox => animate::Cell::new(']', Color::Yellow),
+        Cell::Empty => animate::Cell::new('.', Color::Gray),
+        Cell::Wall => animate::Cell::new('#', Color::Blue),
+        Cell::Robot => animate::Cell::new('@', Color::Red),
+    })
+}
+
+/// `run_robot` one move at a time, in the normal warehouse for part 1 and the wide one for part 2.
+fn frames(input: (Map, Vec<Dir4>), part: Part) -> Frames {
+    let (map, directions) = input;
+    let mut map = match part {
+        Part::One => map,
+        Part::Two => map.doubled(),
+    };
+    let total = directions.len();
+    let first = Frame::new(draw(&map), format!("move 0/{}", total));
+
+    Box::new(
+        iter::once(first).chain(
+            directions
+                .into_iter()
+                .enumerate()
+                .map(move |(i, direction)| {
+                    move_robot(&mut map, direction);
+                    Frame::new(
+                        draw(&map),
+                        format!("move {}/{} {}", i + 1, total, direction.glyph()),
+                    )
+                }),
+        ),
+    )
+}
+
+fn part1(input: &(Map, Vec<Dir4>)) -> impl ToString {
+    run_robot(input.0.clone(), &input.1)
+        .cells
+        .positions(|&cell| cell == Cell::Box)
+        .map(|pos| 100 * pos.row + pos.col)
+        .sum::<usize>()
+}
+
+fn part2(input: &(Map, Vec<Dir4>)) -> impl ToString {
+    run_robot(input.0.doubled(), &input.1)
+        .cells
+        .positions(|&cell| cell == Cell::LeftBox)
+        .map(|pos| 100 * pos.row + pos.col)
+        .sum::<usize>()
+}
+
+/// A walled `size`×`size` warehouse (at least 3×3) full of boxes, then eight moves per cell in
+/// lines of 1000, as in the real input.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(3);
+    let mut map = Grid::filled(size, size, '#');
+
+    for pos in map.coords().collect::<Vec<_>>() {
+        if (1..size - 1).contains(&pos.row) && (1..size - 1).contains(&pos.col) {
+            map[pos] = match rng.below(20) {
+                0 => '#',
+                1..=5 => 'O',
+                _ => '.',
+            };
+        }
+    }
+    let robot = Point::from_row_col(
+        rng.range(1..=size as i64 - 2) as usize,
+        rng.range(1..=size as i64 - 2) as usize,
+    );
+    map[robot] = '@';
+
+    let moves: Vec<char> = (0..size * size * 8)
+        .map(|_| rng.choose(&Dir4::ALL).glyph())
+        .collect();
+    let moves: Vec<String> = moves.chunks(1000).map(String::from_iter).collect();
+
+    format!("{}\n{}\n", map, moves.join("\n"))
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = (Map, Vec<Dir4>);
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 15;
+
+    fn process(input: &str) -> Result<Self::Input, ParseError> {
+        process(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl ToString {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl ToString {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+
+    fn frames(input: Self::Input, part: Part) -> Option<Frames> {
+        Some(frames(input, part))
+    }
+}
+
+common::example_tests!(Day15);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_error() {
+        let err = process(
+            "
+                ####\n\
+                #@x#\n\
+                ####\n\
+                \n\
+                <^\n\
+            ",
+        )
+        .unwrap_err();
+
+        assert_eq!((err.line, err.column), (3, 3));
+        assert_eq!(err.text, "x");
+    }
+
+    #[test]
+    fn test_robot_count() {
+        let err = process("####\n#..#\n####\n\n<^\n").unwrap_err();
+        assert_eq!(err.message, "no robot '@' in the map");
+
+        let err = process("####\n#@.#\n#.@#\n####\n\n<^\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 3));
+        assert_eq!(err.message, "more than one robot '@' in the map");
+    }
+
+    #[test]
+    fn test_frames_replay_run_robot() {
+        let input = process("#####\n#@O.#\n#####\n\n>><\n").unwrap();
+
+        for part in Part::ALL {
+            let frames: Vec<Frame> = frames(input.clone(), part).collect();
+            let map = match part {
+                Part::One => input.0.clone(),
+                Part::Two => input.0.doubled(),
+            };
+
+            assert_eq!(frames.len(), 4);
+            assert_eq!(frames[3].caption, "move 3/3 <");
+            assert_eq!(frames[3].cells, draw(&run_robot(map, &input.1)));
+        }
+    }
+}
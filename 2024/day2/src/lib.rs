@@ -0,0 +1,134 @@
+use common::{ParseError, Rng, Scanner, Solution};
+
+fn process(input: &str) -> Result<Vec<Vec<i8>>, ParseError> {
+    Scanner::within(input, input.trim())
+        .lines()
+        .map(report)
+        .collect()
+}
+
+/// Levels separated by spaces, and nothing else.
+fn report(mut line: Scanner) -> Result<Vec<i8>, ParseError> {
+    let mut levels = vec![line.next_int()?];
+    while !line.rest().trim_end().is_empty() {
+        line.expect_literal(" ")?;
+        levels.push(line.next_int()?);
+    }
+    line.expect_end()?;
+
+    Ok(levels)
+}
+
+fn check_is_safe(report: &[i8]) -> bool {
+    let mut prev = None;
+    for levels in report.windows(2) {
+        let diff = levels[0] - levels[1];
+        if diff == 0 || diff.abs() > 3 {
+            return false;
+        }
+
+        if prev.is_some_and(|prev_diff| prev_diff * diff < 0) {
+            return false;
+        }
+
+        prev = Some(diff)
+    }
+
+    true
+}
+
+#[allow(clippy::ptr_arg)]
+fn part1(input: &Vec<Vec<i8>>) -> impl ToString {
+    input
+        .iter()
+        .map(|report| check_is_safe(report))
+        .filter(|&is_safe| is_safe)
+        .count()
+}
+
+#[allow(clippy::ptr_arg)]
+fn part2(input: &Vec<Vec<i8>>) -> impl ToString {
+    input
+        .iter()
+        .map(|report| {
+            if check_is_safe(report) {
+                return true;
+            }
+
+            (0..report.len()).any(|skipped| {
+                let skipped_report = report
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, &level)| if i == skipped { None } else { Some(level) })
+                    .collect::<Vec<i8>>();
+                check_is_safe(&skipped_report)
+            })
+        })
+        .filter(|&is_safe| is_safe)
+        .count()
+}
+
+/// `size` reports of 5 to 8 levels, mostly steady runs with the odd bad step.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let direction = if rng.chance(0.5) { 1 } else { -1 };
+            let mut level = rng.range(10..=89);
+            let mut levels = vec![level];
+
+            for _ in 1..rng.range(5..=8) {
+                let step = if rng.chance(0.1) {
+                    rng.range(-4..=4)
+                } else {
+                    direction * rng.range(1..=3)
+                };
+                level = (level + step).clamp(1, 99);
+                levels.push(level);
+            }
+
+            let levels: Vec<String> = levels.iter().map(|level| level.to_string()).collect();
+            levels.join(" ") + "\n"
+        })
+        .collect()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Vec<i8>>;
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 2;
+
+    fn process(input: &str) -> Result<Self::Input, ParseError> {
+        process(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl ToString {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl ToString {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+common::example_tests!(Day2);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_error() {
+        assert_eq!(process("7 6  4\n1 2 3 \n").unwrap()[0], [7, 6, 4]);
+
+        let err = process("7 6 4\n1,2;;3\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.text, ",2;;3");
+        assert!(process("1 2 x").is_err());
+    }
+}
@@ -0,0 +1,90 @@
+use common::{ParseError, Rng, Solution};
+use regex::Regex;
+
+fn process(input: &str) -> Result<String, ParseError> {
+    Ok(input.trim().to_owned())
+}
+
+fn part1(input: &str) -> impl ToString {
+    Regex::new(r"mul\(([0-9]{1,3}),([0-9]{1,3})\)")
+        .unwrap()
+        .captures_iter(input)
+        .map(|captures| captures[1].parse::<u32>().unwrap() * captures[2].parse::<u32>().unwrap())
+        .sum::<u32>()
+}
+
+fn part2(input: &str) -> impl ToString {
+    let mut is_enabled = true;
+    Regex::new(r"mul\(([0-9]{1,3}),([0-9]{1,3})\)|don't\(\)|do\(\)")
+        .unwrap()
+        .captures_iter(input)
+        .filter_map(|captures| {
+            match &captures[0] {
+                "do()" => is_enabled = true,
+                "don't()" => is_enabled = false,
+                _ => {
+                    if is_enabled {
+                        return Some(
+                            captures[1].parse::<u32>().unwrap()
+                                * captures[2].parse::<u32>().unwrap(),
+                        );
+                    };
+                }
+            };
+            None
+        })
+        .sum::<u32>()
+}
+
+const JUNK: [&str; 14] = [
+    "mul(", "mul (", ")", ",", "[", "]", "!", "@", "^", "%", " ", "what()", "don't", "do",
+];
+
+/// Corrupted memory with `size` instructions, valid or not, among junk.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut memory = String::new();
+
+    for _ in 0..size {
+        for _ in 0..rng.below(4) {
+            memory.push_str(rng.choose::<&str>(&JUNK));
+        }
+
+        let instruction = match rng.below(10) {
+            0 => "do()".to_owned(),
+            1 => "don't()".to_owned(),
+            2 => format!("mul({},{}]", rng.range(0..=999), rng.range(0..=999)),
+            3 => format!("mul({},{})", rng.range(1000..=9999), rng.range(0..=999)),
+            _ => format!("mul({},{})", rng.range(0..=999), rng.range(0..=999)),
+        };
+        memory.push_str(&instruction);
+    }
+    memory.push('\n');
+
+    memory
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = String;
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 3;
+
+    fn process(input: &str) -> Result<Self::Input, ParseError> {
+        process(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl ToString {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl ToString {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+common::example_tests!(Day3);
@@ -0,0 +1,82 @@
+use common::{Dir8, Grid, ParseError, Point, Rng, Solution, Vec2};
+
+fn process(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input, Some)
+}
+
+fn check_coordinate(
+    grid: &Grid<char>,
+    pos: Point,
+    offset: impl Into<Vec2>,
+    expected: char,
+) -> bool {
+    grid.offset(pos, offset)
+        .is_some_and(|neighbor| grid[neighbor] == expected)
+}
+
+fn part1(input: &Grid<char>) -> impl ToString {
+    let mut count = 0;
+    for pos in input.positions(|&cell| cell == 'X') {
+        'direction: for dir in Dir8::ALL {
+            for (distance, c) in (1..).zip("MAS".chars()) {
+                if !check_coordinate(input, pos, Vec2::from(dir) * distance, c) {
+                    continue 'direction;
+                }
+            }
+            count += 1
+        }
+    }
+
+    count
+}
+
+fn part2(input: &Grid<char>) -> impl ToString {
+    input
+        .positions(|&cell| cell == 'A')
+        .filter(|&pos| {
+            ((check_coordinate(input, pos, Dir8::UpRight, 'M')
+                && check_coordinate(input, pos, Dir8::DownLeft, 'S'))
+                || (check_coordinate(input, pos, Dir8::UpRight, 'S')
+                    && check_coordinate(input, pos, Dir8::DownLeft, 'M')))
+                && ((check_coordinate(input, pos, Dir8::UpLeft, 'M')
+                    && check_coordinate(input, pos, Dir8::DownRight, 'S'))
+                    || (check_coordinate(input, pos, Dir8::UpLeft, 'S')
+                        && check_coordinate(input, pos, Dir8::DownRight, 'M')))
+        })
+        .count()
+}
+
+/// A `size`×`size` grid of X, M, A and S.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let cells = (0..size * size)
+        .map(|_| *rng.choose(&['X', 'M', 'A', 'S']))
+        .collect();
+
+    Grid::new(size, size, cells).to_string()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Grid<char>;
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 4;
+
+    fn process(input: &str) -> Result<Self::Input, ParseError> {
+        process(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl ToString {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl ToString {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+common::example_tests!(Day4);
@@ -0,0 +1,148 @@
+use common::grammar::{preceded, section, separated, uint};
+use common::{graph, ParseError, Parser, Rng, Solution};
+use std::collections::{HashMap, HashSet};
+
+#[allow(clippy::type_complexity)]
+fn process(input: &str) -> Result<(HashMap<u32, HashSet<u32>>, Vec<Vec<u32>>), ParseError> {
+    let rule = (uint(), preceded("|", uint()));
+    let update = separated(uint(), ",");
+    let (rules, updates) = (
+        section(separated(rule, "\n")).context("rules"),
+        section(separated(update, "\n")).context("updates"),
+    )
+        .parse_all(input)?;
+
+    let mut deps: HashMap<u32, HashSet<u32>> = HashMap::new();
+    for (dep, target) in rules {
+        deps.entry(dep).or_default().insert(target);
+    }
+
+    Ok((deps, updates))
+}
+
+fn part1(input: &(HashMap<u32, HashSet<u32>>, Vec<Vec<u32>>)) -> impl ToString {
+    input
+        .1
+        .iter()
+        .filter(|update| {
+            let mut seen: HashSet<u32> = HashSet::new();
+            for item in update.iter() {
+                if let Some(dependants) = input.0.get(item) {
+                    if dependants.intersection(&seen).count() > 0 {
+                        return false;
+                    }
+                }
+                seen.insert(*item);
+            }
+            true
+        })
+        .map(|update| update[update.len() / 2])
+        .sum::<u32>()
+}
+
+/// Updates whose rules go round in a circle have no right order and are left out.
+fn part2(input: &(HashMap<u32, HashSet<u32>>, Vec<Vec<u32>>)) -> impl ToString {
+    input
+        .1
+        .iter()
+        .filter(|update| {
+            let mut seen: HashSet<u32> = HashSet::new();
+            for item in update.iter() {
+                if let Some(dependants) = input.0.get(item) {
+                    if dependants.intersection(&seen).count() > 0 {
+                        return true;
+                    }
+                }
+                seen.insert(*item);
+            }
+            false
+        })
+        .filter_map(|update| {
+            let after = |page: u32| {
+                let later = input.0.get(&page);
+                update
+                    .iter()
+                    .copied()
+                    .filter(move |other| later.is_some_and(|later| later.contains(other)))
+            };
+
+            graph::toposort(update.iter().copied(), after).ok()
+        })
+        .map(|update| update[update.len() / 2])
+        .sum::<u32>()
+}
+
+/// Rules putting 49 pages in a total order, then `size` updates of 5 to 23 of those pages, some
+/// already in order.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut pages: Vec<u32> = (10..100).collect();
+    rng.shuffle(&mut pages);
+    pages.truncate(49);
+
+    let mut rules: Vec<String> = pages
+        .iter()
+        .enumerate()
+        .flat_map(|(i, before)| {
+            pages[i + 1..]
+                .iter()
+                .map(move |after| format!("{}|{}", before, after))
+        })
+        .collect();
+    rng.shuffle(&mut rules);
+
+    let updates: Vec<String> = (0..size)
+        .map(|_| {
+            let mut update = pages.clone();
+            rng.shuffle(&mut update);
+            update.truncate(rng.range(2..=11) as usize * 2 + 1);
+
+            if rng.chance(0.5) {
+                update.sort_by_key(|page| pages.iter().position(|other| other == page));
+            }
+
+            let update: Vec<String> = update.iter().map(|page| page.to_string()).collect();
+            update.join(",")
+        })
+        .collect();
+
+    format!("{}\n\n{}\n", rules.join("\n"), updates.join("\n"))
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (HashMap<u32, HashSet<u32>>, Vec<Vec<u32>>);
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 5;
+
+    fn process(input: &str) -> Result<Self::Input, ParseError> {
+        process(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl ToString {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl ToString {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+common::example_tests!(Day5);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rules_cycle() {
+        let input = process("1|2\n2|3\n3|1\n4|5\n4|6\n5|6\n\n3,2,1\n6,4,5\n").unwrap();
+
+        assert_eq!(part1(&input).to_string(), "0");
+        assert_eq!(part2(&input).to_string(), "5");
+    }
+}
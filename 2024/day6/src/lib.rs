@@ -0,0 +1,184 @@
+use common::{
+    parallel, Cell, Color, Dir4, Frame, Frames, Grid, Image, ParseError, Part, Point, Rgb, Rng,
+    Solution,
+};
+use std::collections::HashSet;
+use std::iter;
+
+fn process(input: &str) -> Result<(Grid<bool>, Point), ParseError> {
+    let grid = Grid::parse(input, |symbol| match symbol {
+        '^' | '#' | '.' => Some(symbol),
+        _ => None,
+    })?;
+
+    let guards: Vec<Point> = grid.positions(|&symbol| symbol == '^').collect();
+    let guard = match guards[..] {
+        [guard] => guard,
+        [] => return Err(ParseError::at_end(input, "no guard '^' in the grid")),
+        _ => {
+            let (second, _) = input.match_indices('^').nth(1).unwrap();
+            return Err(ParseError::at(
+                input,
+                &input[second..second + 1],
+                "more than one guard '^' in the grid",
+            ));
+        }
+    };
+
+    Ok((grid.map(|&symbol| symbol == '#'), guard))
+}
+
+/// Where the guard is after one more step, or `None` once they walk off the map.
+fn step(obstacles: &Grid<bool>, pos: Point, dir: Dir4) -> Option<(Point, Dir4)> {
+    let next_pos = obstacles.offset(pos, dir)?;
+
+    if obstacles[next_pos] {
+        Some((pos, dir.turn_right()))
+    } else {
+        Some((next_pos, dir))
+    }
+}
+
+fn walk(obstacles: &Grid<bool>, starting_pos: &Point) -> (bool, HashSet<Point>) {
+    let mut guard_dir = Dir4::Up;
+    let mut guard_pos = *starting_pos;
+    let mut seen: HashSet<(Dir4, Point)> = HashSet::new();
+
+    let looped = loop {
+        if !seen.insert((guard_dir, guard_pos)) {
+            break true;
+        }
+
+        match step(obstacles, guard_pos, guard_dir) {
+            Some((pos, dir)) => (guard_pos, guard_dir) = (pos, dir),
+            None => break false,
+        }
+    };
+
+    (looped, seen.iter().map(|&(_, pos)| pos).collect())
+}
+
+fn part1(input: &(Grid<bool>, Point)) -> impl ToString {
+    walk(&input.0, &input.1).1.len()
+}
+
+fn part2(input: &(Grid<bool>, Point)) -> impl ToString {
+    let candidates: Vec<Point> = walk(&input.0, &input.1)
+        .1
+        .into_iter()
+        .filter(|&pos| pos != input.1)
+        .collect();
+
+    parallel::count(&candidates, |&pos| {
+        let mut obstacles = input.0.clone();
+        obstacles[pos] = true;
+
+        walk(&obstacles, &input.1).0
+    })
+}
+
+/// The part 1 walk, one step per frame, until the guard leaves or starts going round in circles.
+fn frames(input: (Grid<bool>, Point)) -> Frames {
+    let (obstacles, start) = input;
+    let mut visited = obstacles.map(|_| false);
+    let mut seen = HashSet::new();
+    let (mut guard, mut steps, mut count) = (Some((start, Dir4::Up)), 0, 0);
+
+    Box::new(iter::from_fn(move || {
+        let (pos, dir) = guard.filter(|&(pos, dir)| seen.insert((dir, pos)))?;
+        if !visited[pos] {
+            visited[pos] = true;
+            count += 1;
+        }
+
+        let mut cells = obstacles.map(|&obstacle| match obstacle {
+            true => Cell::new('#', Color::Blue),
+            false => Cell::new('.', Color::Gray),
+        });
+        for (pos, _) in visited.iter().filter(|(_, &visited)| visited) {
+            cells[pos] = Cell::new('X', Color::Cyan);
+        }
+        cells[pos] = Cell::new(dir.glyph(), Color::Yellow);
+
+        let frame = Frame::new(cells, format!("step {}, {} cells visited", steps, count));
+        guard = step(&obstacles, pos, dir);
+        steps += 1;
+
+        Some(frame)
+    }))
+}
+
+/// A `size`×`size` lab with scattered obstacles and exactly one guard.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut grid = Grid::filled(size, size, '.');
+    for pos in grid.coords().collect::<Vec<_>>() {
+        if rng.chance(0.05) {
+            grid[pos] = '#';
+        }
+    }
+
+    let guard = Point::from_row_col(rng.below(size), rng.below(size));
+    grid[guard] = '^';
+
+    grid.to_string()
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = (Grid<bool>, Point);
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 6;
+
+    fn process(input: &str) -> Result<Self::Input, ParseError> {
+        process(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl ToString {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl ToString {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+
+    fn frames(input: Self::Input, _part: Part) -> Option<Frames> {
+        Some(frames(input))
+    }
+
+    /// The guard's path in part 1.
+    fn image(input: &Self::Input, _part: Part) -> Option<Image> {
+        let visited = walk(&input.0, &input.1).1;
+        let mut cells = input.0.map(|&obstacle| match obstacle {
+            true => Rgb(36, 114, 200),
+            false => Rgb::BLACK,
+        });
+        for pos in visited {
+            cells[pos] = Rgb(17, 168, 205);
+        }
+        cells[input.1] = Rgb(245, 245, 67);
+
+        Some(Image::from_grid(&cells, |&rgb| rgb))
+    }
+}
+
+common::example_tests!(Day6);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guard_count() {
+        let err = process("..#\n...\n").unwrap_err();
+        assert_eq!(err.message, "no guard '^' in the grid");
+
+        let err = process("^.#\n..^\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.message, "more than one guard '^' in the grid");
+    }
+}
@@ -0,0 +1,165 @@
+use common::grammar::{lines, preceded, separated, spaces, uint};
+use common::{math, parallel, ParseError, Parser, Rng, Solution};
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Add,
+    Mul,
+    Cat,
+}
+
+impl Op {
+    /// `None` if the result doesn't fit in a `u64`, which puts it past any target.
+    fn apply(self, total: u64, value: u64) -> Option<u64> {
+        match self {
+            Op::Add => total.checked_add(value),
+            Op::Mul => total.checked_mul(value),
+            Op::Cat => math::checked_concat(total, value, 10),
+        }
+    }
+}
+
+fn process(input: &str) -> Result<Vec<(u64, Vec<u64>)>, ParseError> {
+    let equation = (uint(), preceded(":", separated(uint(), spaces())));
+
+    lines(equation).parse_all(input)
+}
+
+#[allow(clippy::ptr_arg)]
+fn part1(input: &Vec<(u64, Vec<u64>)>) -> impl ToString {
+    parallel::filter(input, |(target, values)| {
+        let num_operations = (values.len() - 1) as u32;
+        (0..(2u64.pow(num_operations)))
+            .map(|int| {
+                (0..num_operations)
+                    .map(|shifts| match (int >> shifts) & 1 {
+                        0 => Op::Add,
+                        1 => Op::Mul,
+                        _ => unreachable!(),
+                    })
+                    .collect::<Vec<Op>>()
+            })
+            .any(|ops| {
+                values[1..]
+                    .iter()
+                    .zip(&ops)
+                    .try_fold(values[0], |total, (&value, op)| op.apply(total, value))
+                    == Some(*target)
+            })
+    })
+    .into_iter()
+    .map(|(target, _)| target)
+    .sum::<u64>()
+}
+
+#[allow(clippy::ptr_arg)]
+fn part2(input: &Vec<(u64, Vec<u64>)>) -> impl ToString {
+    parallel::filter(input, |(target, values)| {
+        let num_operations = (values.len() - 1) as u32;
+        (0..(3u64.pow(num_operations)))
+            .map(|int| {
+                (0..num_operations)
+                    .map(|shifts| match (int / 3u64.pow(shifts)) % 3 {
+                        0 => Op::Add,
+                        1 => Op::Mul,
+                        2 => Op::Cat,
+                        _ => unreachable!(),
+                    })
+                    .collect::<Vec<Op>>()
+            })
+            .any(|ops| {
+                values[1..]
+                    .iter()
+                    .zip(&ops)
+                    .try_fold(values[0], |total, (&value, op)| op.apply(total, value))
+                    == Some(*target)
+            })
+    })
+    .into_iter()
+    .map(|(target, _)| target)
+    .sum::<u64>()
+}
+
+/// `size` equations of 2 to 12 numbers, about half of them solvable. No equation has more than
+/// 18 digits in total, so every operator combination fits in a `u64`.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let count = rng.range(2..=12) as u32;
+            let mut digits_left = 18;
+
+            let values: Vec<u64> = (0..count)
+                .map(|i| {
+                    let digits =
+                        rng.range(1..=(digits_left - (count - i - 1)).min(3) as i64) as u32;
+                    digits_left -= digits;
+
+                    rng.range(10i64.pow(digits - 1)..=10i64.pow(digits) - 1) as u64
+                })
+                .collect();
+
+            let mut target = values[0];
+            for &value in &values[1..] {
+                target = match rng.below(3) {
+                    0 => target + value,
+                    1 => target * value,
+                    _ => math::concat(target, value, 10),
+                };
+            }
+            if rng.chance(0.5) {
+                target += 1;
+            }
+
+            let values: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+            format!("{}: {}\n", target, values.join(" "))
+        })
+        .collect()
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<(u64, Vec<u64>)>;
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 7;
+
+    fn process(input: &str) -> Result<Self::Input, ParseError> {
+        process(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl ToString {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl ToString {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+common::example_tests!(Day7);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_spacing() {
+        assert_eq!(
+            process("3: 1  2 \n4:\t2 2\n").unwrap(),
+            vec![(3, vec![1, 2]), (4, vec![2, 2])]
+        );
+        assert_eq!(process("3: 1 2\n4: 2 x\n").unwrap_err().line, 2);
+    }
+
+    #[test]
+    fn test_overflow_is_no_match() {
+        let input = process("10: 99999999999 99999999999\n11: 1 1\n2: 1 1\n").unwrap();
+
+        assert_eq!(part1(&input).to_string(), "2");
+        assert_eq!(part2(&input).to_string(), "13");
+    }
+}
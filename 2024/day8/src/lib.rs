@@ -0,0 +1,109 @@
+use common::{Grid, ParseError, Point, Rng, Solution};
+use std::collections::{HashMap, HashSet};
+
+#[allow(clippy::type_complexity)]
+fn process(input: &str) -> Result<(Grid<char>, HashMap<char, HashSet<Point>>), ParseError> {
+    let grid = Grid::parse(input, Some)?;
+
+    let antennae = grid.positions(|c| c.is_alphanumeric()).fold(
+        HashMap::new(),
+        |mut acc: HashMap<char, HashSet<Point>>, pos| {
+            acc.entry(grid[pos]).or_default().insert(pos);
+            acc
+        },
+    );
+
+    Ok((grid, antennae))
+}
+
+#[allow(clippy::type_complexity)]
+fn part1(input: &(Grid<char>, HashMap<char, HashSet<Point>>)) -> impl ToString {
+    let mut antinodes: HashSet<Point> = HashSet::new();
+
+    input.1.values().for_each(|antennae| {
+        antennae.iter().for_each(|antenna| {
+            antennae.iter().for_each(|other| {
+                if antenna == other {
+                    return;
+                }
+
+                let diff = *antenna - *other;
+
+                antinodes.extend(input.0.offset(*antenna, diff));
+                antinodes.extend(input.0.offset(*other, -diff));
+            })
+        });
+    });
+
+    antinodes.len()
+}
+
+#[allow(clippy::type_complexity)]
+fn part2(input: &(Grid<char>, HashMap<char, HashSet<Point>>)) -> impl ToString {
+    let mut antinodes: HashSet<Point> = HashSet::new();
+
+    input.1.values().for_each(|antennae| {
+        antennae.iter().for_each(|antenna| {
+            antennae.iter().for_each(|other| {
+                if antenna == other {
+                    return;
+                }
+
+                let diff = *antenna - *other;
+
+                for step in [diff, -diff] {
+                    let mut proposed = Some(*antenna);
+                    while let Some(pos) = proposed {
+                        antinodes.insert(pos);
+                        proposed = input.0.offset(pos, step);
+                    }
+                }
+            })
+        });
+    });
+
+    antinodes.len()
+}
+
+const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// A `size`×`size` map where about one cell in fifty is an antenna.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let cells = (0..size * size)
+        .map(|_| {
+            if rng.chance(0.02) {
+                *rng.choose(FREQUENCIES) as char
+            } else {
+                '.'
+            }
+        })
+        .collect();
+
+    Grid::new(size, size, cells).to_string()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = (Grid<char>, HashMap<char, HashSet<Point>>);
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 8;
+
+    fn process(input: &str) -> Result<Self::Input, ParseError> {
+        process(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl ToString {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl ToString {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+common::example_tests!(Day8);
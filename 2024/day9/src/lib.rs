@@ -1,19 +1,25 @@
+use common::{ParseError, Rng, Solution};
 use std::iter::repeat_n;
 
-const INPUT: &str = include_str!("../input.txt");
+#[cfg(test)]
+mod reference;
 
-fn process(input: &str) -> (Vec<usize>, Vec<usize>) {
-    input
-        .trim()
+fn process(input: &str) -> Result<(Vec<usize>, Vec<usize>), ParseError> {
+    let trimmed = input.trim();
+    trimmed
         .char_indices()
-        .fold((vec![], vec![]), |(mut files, mut spaces), (i, c)| {
+        .try_fold((vec![], vec![]), |(mut files, mut spaces), (i, c)| {
+            let digit = c.to_digit(10).ok_or_else(|| {
+                ParseError::at(input, &trimmed[i..i + c.len_utf8()], "expected a digit")
+            })? as usize;
+
             if i % 2 == 0 {
-                files.push(c.to_digit(10).unwrap() as usize);
+                files.push(digit);
             } else {
-                spaces.push(c.to_digit(10).unwrap() as usize);
+                spaces.push(digit);
             };
 
-            (files, spaces)
+            Ok((files, spaces))
         })
 }
 
@@ -93,30 +99,58 @@ fn part2(input: &(Vec<usize>, Vec<usize>)) -> impl ToString {
     total
 }
 
-fn main() {
-    println!("Processing input");
-    let input = process(INPUT);
-    println!("------");
+/// A disk map of `size` digits, alternating files of 1 to 9 blocks and gaps of 0 to 9.
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut disk_map: String = (0..size)
+        .map(|i| {
+            let digit = if i % 2 == 0 {
+                rng.range(1..=9)
+            } else {
+                rng.range(0..=9)
+            };
 
-    println!("Running part 1");
-    println!("Result: {}", part1(&input).to_string());
-    println!("------");
+            char::from_digit(digit as u32, 10).unwrap()
+        })
+        .collect();
+    disk_map.push('\n');
 
-    println!("Running part 2");
-    println!("Result: {}", part2(&input).to_string());
+    disk_map
 }
 
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = (Vec<usize>, Vec<usize>);
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 9;
+
+    fn process(input: &str) -> Result<Self::Input, ParseError> {
+        process(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl ToString {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl ToString {
+        part2(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+common::example_tests!(Day9);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use common::{differential, Part};
 
     #[test]
-    fn test_part1() {
-        assert_eq!(part1(&process("2333133121414131402")).to_string(), "1928");
-    }
-
-    #[test]
-    fn test_part2() {
-        assert_eq!(part2(&process("2333133121414131402")).to_string(), "2858");
+    fn test_matches_reference() {
+        differential::compare::<Day9, _>(Part::One, reference::part1, 200, 41);
+        differential::compare::<Day9, _>(Part::Two, reference::part2, 200, 41);
     }
 }
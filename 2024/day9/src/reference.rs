@@ -0,0 +1,59 @@
+//! Naive solutions that simulate every block on the disk.
+
+fn blocks(input: &(Vec<usize>, Vec<usize>)) -> Vec<Option<usize>> {
+    let mut blocks = vec![];
+    for (id, &len) in input.0.iter().enumerate() {
+        blocks.extend(std::iter::repeat_n(Some(id), len));
+        blocks.extend(std::iter::repeat_n(
+            None,
+            input.1.get(id).copied().unwrap_or(0),
+        ));
+    }
+
+    blocks
+}
+
+fn checksum(blocks: &[Option<usize>]) -> usize {
+    blocks
+        .iter()
+        .enumerate()
+        .map(|(i, block)| i * block.unwrap_or(0))
+        .sum()
+}
+
+pub fn part1(input: &(Vec<usize>, Vec<usize>)) -> usize {
+    let mut blocks = blocks(input);
+
+    loop {
+        let free = blocks.iter().position(Option::is_none);
+        let last = blocks.iter().rposition(Option::is_some);
+        match (free, last) {
+            (Some(free), Some(last)) if free < last => blocks.swap(free, last),
+            _ => break,
+        }
+    }
+
+    checksum(&blocks)
+}
+
+pub fn part2(input: &(Vec<usize>, Vec<usize>)) -> usize {
+    let mut blocks = blocks(input);
+
+    for id in (0..input.0.len()).rev() {
+        let Some(start) = blocks.iter().position(|&block| block == Some(id)) else {
+            continue;
+        };
+        let len = input.0[id];
+
+        let free = (0..start)
+            .filter(|&i| i + len <= start)
+            .find(|&i| blocks[i..i + len].iter().all(Option::is_none));
+        if let Some(free) = free {
+            for offset in 0..len {
+                blocks.swap(free + offset, start + offset);
+            }
+        }
+    }
+
+    checksum(&blocks)
+}
@@ -0,0 +1,35 @@
+use common::Year;
+
+pub const YEAR: Year = Year {
+    year: 2024,
+    days: &[
+        &day0::Day0,
+        &day1::Day1,
+        &day2::Day2,
+        &day3::Day3,
+        &day4::Day4,
+        &day5::Day5,
+        &day6::Day6,
+        &day7::Day7,
+        &day8::Day8,
+        &day9::Day9,
+        &day10::Day10,
+        &day11::Day11,
+        &day12::Day12,
+        &day13::Day13,
+        &day14::Day14,
+        &day15::Day15,
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_belong_to_the_year() {
+        for day in YEAR.days {
+            assert_eq!(day.year(), YEAR.year, "day {}", day.day());
+        }
+    }
+}
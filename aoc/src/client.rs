@@ -0,0 +1,240 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use common::{Feedback, Part};
+
+use crate::http;
+
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const URL_VAR: &str = "AOC_URL";
+
+const DEFAULT_URL: &str = "https://adventofcode.com";
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    Wait(Duration),
+    WrongLevel,
+    Unrecognised(String),
+}
+
+impl Outcome {
+    fn parse(html: &str) -> Self {
+        let text = article_text(html);
+
+        if text.contains("That's the right answer") {
+            Outcome::Correct
+        } else if text.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if text.contains("your answer is too low") {
+            Outcome::TooLow
+        } else if text.contains("That's not the right answer") {
+            Outcome::Wrong
+        } else if text.contains("You gave an answer too recently") {
+            Outcome::Wait(parse_wait(&text).unwrap_or_default())
+        } else if text.contains("You don't seem to be solving the right level") {
+            Outcome::WrongLevel
+        } else {
+            Outcome::Unrecognised(text)
+        }
+    }
+
+    /// The verdict, if the answer was actually judged.
+    pub fn feedback(&self) -> Option<Feedback> {
+        match self {
+            Outcome::Correct => Some(Feedback::Correct),
+            Outcome::TooHigh => Some(Feedback::TooHigh),
+            Outcome::TooLow => Some(Feedback::TooLow),
+            Outcome::Wrong => Some(Feedback::Wrong),
+            Outcome::Wait(_) | Outcome::WrongLevel | Outcome::Unrecognised(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "wrong, too high"),
+            Outcome::TooLow => write!(f, "wrong, too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::Wait(left) => write!(f, "submitted too recently, wait {}s", left.as_secs()),
+            Outcome::WrongLevel => write!(f, "not the current level, is it already solved?"),
+            Outcome::Unrecognised(text) => write!(f, "unrecognised response: {}", text),
+        }
+    }
+}
+
+/// The text of the response's `<article>`, without tags.
+fn article_text(html: &str) -> String {
+    let article = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reads "You have 1m 23s left to wait".
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+
+    text[start..end]
+        .split_whitespace()
+        .try_fold(0, |seconds, part| {
+            let (number, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let number: u64 = number.parse().ok()?;
+
+            match unit {
+                "h" => Some(seconds + number * 3600),
+                "m" => Some(seconds + number * 60),
+                "s" => Some(seconds + number),
+                _ => None,
+            }
+        })
+        .map(Duration::from_secs)
+}
+
+/// Where `year`'s puzzles live and who we are, from `$AOC_URL` and `$AOC_SESSION` (or a
+/// `.session` file in the workspace).
+#[derive(Debug, Clone)]
+pub struct Site {
+    pub url: String,
+    pub year: u16,
+    pub session: String,
+}
+
+impl Site {
+    pub fn from_env(root: &Path, year: u16) -> Result<Self, String> {
+        let session = match env::var(SESSION_VAR) {
+            Ok(session) => session,
+            Err(_) => fs::read_to_string(root.join(".session")).map_err(|_| {
+                format!(
+                    "no session cookie: set ${} or write it to .session",
+                    SESSION_VAR
+                )
+            })?,
+        };
+
+        Ok(Self {
+            url: env::var(URL_VAR).unwrap_or_else(|_| DEFAULT_URL.to_owned()),
+            year,
+            session: session.trim().to_owned(),
+        })
+    }
+
+    fn day_url(&self, day: u8) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.url.trim_end_matches('/'),
+            self.year,
+            day
+        )
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    pub fn fetch_input(&self, day: u8) -> Result<String, String> {
+        let url = format!("{}/input", self.day_url(day));
+        let response = http::send("GET", &url, &[("Cookie", &self.cookie())], None)
+            .map_err(|err| format!("could not fetch {}: {}", url, err))?;
+
+        match response.status {
+            200 => Ok(response.body),
+            status => Err(format!(
+                "fetching {} failed with {}: {}",
+                url,
+                status,
+                response.body.trim()
+            )),
+        }
+    }
+
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Outcome, String> {
+        let url = format!("{}/answer", self.day_url(day));
+        let form = format!("level={}&answer={}", part, form_encode(answer));
+        let response = http::send(
+            "POST",
+            &url,
+            &[
+                ("Cookie", &self.cookie()),
+                ("Content-Type", "application/x-www-form-urlencoded"),
+            ],
+            Some(&form),
+        )
+        .map_err(|err| format!("could not submit to {}: {}", url, err))?;
+
+        match response.status {
+            200 => Ok(Outcome::parse(&response.body)),
+            status => Err(format!("submitting to {} failed with {}", url, status)),
+        }
+    }
+}
+
+/// `value` percent-encoded for a form body, so `&`, `=`, `+` and the like stay part of it.
+pub fn form_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_outcome() {
+        let page = |text: &str| format!("<main>\n<article><p>{}</p></article>\n</main>", text);
+
+        assert_eq!(
+            Outcome::parse(&page(
+                "That's the right answer! You are <em>one gold star</em> closer."
+            )),
+            Outcome::Correct
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Outcome::TooLow
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 1m 23s left to wait."
+            )),
+            Outcome::Wait(Duration::from_secs(83))
+        );
+        assert_eq!(
+            Outcome::parse(&page("Something new")),
+            Outcome::Unrecognised("Something new".to_owned())
+        );
+    }
+}
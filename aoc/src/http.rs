@@ -0,0 +1,123 @@
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+use std::process::{Command, Stdio};
+
+const USER_AGENT: &str = concat!("aoc-runner/", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+fn invalid(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
+
+/// Sends one request. Plain `http://` URLs are spoken directly over a socket; anything else
+/// (the real site is HTTPS only) goes through `curl`, since we have no TLS of our own.
+pub fn send(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> io::Result<Response> {
+    match url.strip_prefix("http://") {
+        Some(rest) => send_plain(method, rest, headers, body),
+        None => send_curl(method, url, headers, body),
+    }
+}
+
+fn send_plain(
+    method: &str,
+    rest: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> io::Result<Response> {
+    let (host, path) = match rest.find('/') {
+        Some(slash) => rest.split_at(slash),
+        None => (rest, "/"),
+    };
+    let address = if host.contains(':') {
+        host.to_owned()
+    } else {
+        format!("{}:80", host)
+    };
+
+    let mut request = format!(
+        "{} {} HTTP/1.1\r\nHost: {}\r\nUser-Agent: {}\r\nConnection: close\r\n",
+        method, path, host, USER_AGENT
+    );
+    for (name, value) in headers {
+        request.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    let body = body.unwrap_or("");
+    request.push_str(&format!("Content-Length: {}\r\n\r\n{}", body.len(), body));
+
+    let mut stream = TcpStream::connect(address)?;
+    stream.write_all(request.as_bytes())?;
+    let mut raw = String::new();
+    stream.read_to_string(&mut raw)?;
+
+    let (head, body) = raw
+        .split_once("\r\n\r\n")
+        .ok_or_else(|| invalid("response has no header terminator"))?;
+    let status = head
+        .split(' ')
+        .nth(1)
+        .and_then(|status| status.parse().ok())
+        .ok_or_else(|| invalid(format!("bad status line {:?}", head.lines().next())))?;
+
+    Ok(Response {
+        status,
+        body: body.to_owned(),
+    })
+}
+
+fn send_curl(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> io::Result<Response> {
+    let mut command = Command::new("curl");
+    command.args(["--silent", "--show-error", "--request", method]);
+    command.args(["--user-agent", USER_AGENT]);
+    // Headers go in on stdin, as anyone can read a process's arguments and the cookie is secret.
+    command.args(["--header", "@-"]);
+    if let Some(body) = body {
+        command.args(["--data-binary", body]);
+    }
+    command.args(["--write-out", "\n%{http_code}", url]);
+
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    for (name, value) in headers {
+        writeln!(stdin, "{}: {}", name, value)?;
+    }
+    drop(stdin);
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(format!(
+            "curl failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    let stdout = String::from_utf8(output.stdout).map_err(|_| invalid("response is not UTF-8"))?;
+    let (body, status) = stdout
+        .rsplit_once('\n')
+        .ok_or_else(|| invalid("curl printed no status"))?;
+
+    Ok(Response {
+        status: status
+            .trim()
+            .parse()
+            .map_err(|_| invalid(format!("bad status {:?}", status)))?,
+        body: body.to_owned(),
+    })
+}
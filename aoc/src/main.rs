@@ -0,0 +1,687 @@
+mod client;
+mod http;
+mod mock;
+mod player;
+mod scaffold;
+mod years;
+
+use std::env;
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::time::Duration;
+
+use client::{Outcome, Site, SESSION_VAR, URL_VAR};
+use common::{
+    crate_dir, day_dir, memory, parallel, Answers, DynSolution, Expected, Format, Frame, GifWriter,
+    InputSource, Part, Rng, Submission, SubmissionLog, Verdict, INPUT_DIR_VAR,
+};
+
+const YEAR_VAR: &str = "AOC_YEAR";
+/// The first Advent of Code; smaller numbers on the command line are days.
+const FIRST_YEAR: u16 = 2015;
+
+const USAGE: &str = "\
+Usage:
+    aoc run [YEAR] [DAY] [--part 1|2] [--input PATH|-] [--format human|json|tsv|csv]
+        Run one day, or every day of the year when DAY is omitted, warning about answers that
+        YEAR/dayN/submissions.txt shows to be wrong. Formats other than human print one row
+        per day and part: day, part, answer, input_sha256, process_ns, part_ns, year
+    aoc bench [YEAR] [DAY] [--iterations N] [--input PATH|-] [--json]
+        Time process, part 1 and part 2 separately over N iterations (default 10)
+    aoc alloc [YEAR] [DAY] [--input PATH|-] [--json]
+        Count the bytes allocated, allocations made and peak extra memory of process, part 1
+        and part 2. Needs a build with `--features alloc-stats`
+    aoc verify [YEAR] [DAY] [--part 1|2] [--input PATH|-]
+        Check answers against YEAR/dayN/answers.txt, failing on regressions or missing answers
+    aoc record [YEAR] DAY [--part 1|2] [--input PATH|-] [--plain]
+        Store the current answers in YEAR/dayN/answers.txt, salted and hashed unless --plain
+    aoc generate [YEAR] DAY [--size N] [--seed N]
+        Print a random input for DAY; what N counts (default 100) depends on the day
+    aoc animate [YEAR] DAY [--part 1|2] [--fps N] [--output DIR] [--scale N]
+        Replay DAY's simulation in the terminal at N frames per second (default 20).
+        Space pauses, n steps, + and - change speed, q quits. With --output, write every
+        frame to DIR/frame-NNNNN.png instead, each cell N×N pixels (default 4)
+    aoc gif [YEAR] DAY [--part 1|2] [--output PATH] [--scale N] [--delay MS] [--every N]
+            [--from N] [--to N] [--max-frames N]
+        Record DAY's simulation to PATH (default dayN.gif), MS milliseconds per frame (default
+        50, rounded to 10ms), keeping every Nth of frames --from to --to and stopping after
+        --max-frames (default 1000). The window's last frame is always kept
+    aoc image [YEAR] DAY [--part 1|2] [--output PATH] [--scale N]
+        Draw DAY's grid to PATH (default dayN.png); .pbm, .pgm, .ppm and .png are supported
+    aoc new [YEAR] DAY
+        Create YEAR/dayN/ from the template and register it with its year's crate, creating
+        and registering that too if it is the year's first day
+    aoc fetch [YEAR] DAY
+        Download DAY's input to where `run` looks for it, unless it is already there
+    aoc submit [YEAR] DAY PART ANSWER
+        Submit an answer unless YEAR/dayN/submissions.txt rules it out, logging the verdict
+        there and recording the answer in YEAR/dayN/answers.txt if it is correct
+    aoc mock-server [--port N]
+        Serve aoc/fixtures on 127.0.0.1:N (default 8024) the way the site would
+
+run, bench, alloc, verify and record take --threads N to cap the worker threads used by parallel
+parts (default: one per core). Results do not depend on it.
+
+YEAR defaults to $AOC_YEAR, otherwise to the latest year with solutions.
+
+Inputs are read from --input (`-` for stdin), otherwise from
+$AOC_INPUT_DIR/YEAR/dayN/input.txt, otherwise from YEAR/dayN/input.txt in the workspace.
+Answers and submissions always live in YEAR/dayN in the workspace.
+
+fetch and submit use the session cookie in $AOC_SESSION or .session and the site in $AOC_URL
+(default https://adventofcode.com).";
+
+type Command = fn(&Options) -> Result<(), String>;
+
+struct Options {
+    year: Option<u16>,
+    day: Option<u8>,
+    parts: Vec<Part>,
+    source: Option<InputSource>,
+    iterations: usize,
+    format: Format,
+    json: bool,
+    plain: bool,
+    size: usize,
+    seed: u64,
+    port: u16,
+    fps: u32,
+    output: Option<PathBuf>,
+    scale: usize,
+    delay: u16,
+    every: usize,
+    from: usize,
+    to: Option<usize>,
+    max_frames: usize,
+    threads: Option<usize>,
+    rest: Vec<String>,
+}
+
+fn positive<T: FromStr + PartialOrd + Default>(raw: &str, what: &str) -> Result<T, String> {
+    raw.parse()
+        .ok()
+        .filter(|value| *value > T::default())
+        .ok_or(format!("invalid {} {:?}", what, raw))
+}
+
+impl Options {
+    /// `positionals` is how many arguments the command takes after DAY.
+    fn parse(args: &[String], positionals: usize) -> Result<Self, String> {
+        let mut options = Options {
+            year: None,
+            day: None,
+            parts: Part::ALL.to_vec(),
+            source: None,
+            iterations: 10,
+            format: Format::Human,
+            json: false,
+            plain: false,
+            size: 100,
+            seed: 0,
+            port: 8024,
+            fps: 20,
+            output: None,
+            scale: 4,
+            delay: 50,
+            every: 1,
+            from: 0,
+            to: None,
+            max_frames: 1000,
+            threads: None,
+            rest: vec![],
+        };
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| args.next().ok_or(format!("{} needs a value", name));
+
+            match arg.as_str() {
+                "--part" | "-p" => options.parts = vec![value("--part")?.parse()?],
+                "--input" | "-i" => options.source = Some(InputSource::from_arg(value("--input")?)),
+                "--iterations" | "-n" => {
+                    options.iterations = positive(value("--iterations")?, "iteration count")?;
+                }
+                "--size" => options.size = positive(value("--size")?, "size")?,
+                "--seed" => {
+                    let raw = value("--seed")?;
+                    options.seed = raw.parse().map_err(|_| format!("invalid seed {:?}", raw))?;
+                }
+                "--port" => {
+                    let raw = value("--port")?;
+                    options.port = raw.parse().map_err(|_| format!("invalid port {:?}", raw))?;
+                }
+                "--fps" => options.fps = positive(value("--fps")?, "frame rate")?,
+                "--delay" => options.delay = positive(value("--delay")?, "delay")?,
+                "--every" => options.every = positive(value("--every")?, "frame step")?,
+                "--from" => {
+                    let raw = value("--from")?;
+                    options.from = raw
+                        .parse()
+                        .map_err(|_| format!("invalid frame {:?}", raw))?;
+                }
+                "--to" => {
+                    let raw = value("--to")?;
+                    options.to = Some(
+                        raw.parse()
+                            .map_err(|_| format!("invalid frame {:?}", raw))?,
+                    );
+                }
+                "--max-frames" => {
+                    options.max_frames = positive(value("--max-frames")?, "frame count")?;
+                }
+                "--output" | "-o" => options.output = Some(PathBuf::from(value("--output")?)),
+                "--scale" => options.scale = positive(value("--scale")?, "scale")?,
+                "--threads" | "-j" => {
+                    options.threads = Some(positive(value("--threads")?, "thread count")?);
+                }
+                "--format" | "-f" => options.format = value("--format")?.parse()?,
+                "--json" => options.json = true,
+                "--plain" => options.plain = true,
+                raw if options.year.is_none() && options.day.is_none() && raw.len() == 4 => {
+                    options.year = Some(
+                        raw.parse()
+                            .ok()
+                            .filter(|&year| year >= FIRST_YEAR)
+                            .ok_or(format!("invalid year {:?}", raw))?,
+                    );
+                }
+                raw if options.day.is_none() => {
+                    options.day = Some(raw.parse().map_err(|_| format!("invalid day {:?}", raw))?);
+                }
+                raw if options.rest.len() < positionals => options.rest.push(raw.to_owned()),
+                raw => return Err(format!("unexpected argument {:?}", raw)),
+            }
+        }
+
+        if let Some(threads) = options.threads {
+            parallel::set_threads(threads);
+        }
+
+        Ok(options)
+    }
+
+    /// YEAR from the command line, otherwise `$AOC_YEAR`, otherwise the latest one we have.
+    fn year(&self) -> Result<u16, String> {
+        if let Some(year) = self.year {
+            return Ok(year);
+        }
+
+        match env::var(YEAR_VAR) {
+            Ok(raw) => raw
+                .parse()
+                .ok()
+                .filter(|&year| year >= FIRST_YEAR)
+                .ok_or(format!("invalid ${} {:?}", YEAR_VAR, raw)),
+            Err(_) => Ok(years::latest().year),
+        }
+    }
+
+    fn selected(&self) -> Result<Vec<&'static dyn DynSolution>, String> {
+        let year = self.year()?;
+        let solutions = years::find(year).ok_or(format!("no solutions for {}", year))?;
+
+        match self.day {
+            Some(day) => Ok(vec![solutions
+                .find(day)
+                .ok_or(format!("no solution for {} day {}", year, day))?]),
+            None if self.source.is_some() => Err("--input needs a single DAY".to_owned()),
+            None => Ok(solutions.days.to_vec()),
+        }
+    }
+
+    fn read_input(&self, solution: &dyn DynSolution) -> Result<String, String> {
+        let (year, day) = (solution.year(), solution.day());
+        let source = self
+            .source
+            .clone()
+            .unwrap_or_else(|| InputSource::default_for(year, day, workspace_root()));
+
+        source.read().map_err(|err| {
+            format!(
+                "could not read input for {} day {}: {} (set --input or ${})",
+                year, day, err, INPUT_DIR_VAR
+            )
+        })
+    }
+}
+
+fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+fn answers_path(year: u16, day: u8) -> PathBuf {
+    crate_dir(year, day, workspace_root()).join("answers.txt")
+}
+
+fn submissions_path(year: u16, day: u8) -> PathBuf {
+    crate_dir(year, day, workspace_root()).join("submissions.txt")
+}
+
+/// Warns about any result that earlier submissions show to be wrong. Only the real input's
+/// answers were ever submitted, so other inputs are not checked.
+fn warn_known_wrong(
+    options: &Options,
+    solution: &dyn DynSolution,
+    results: &[(Part, String)],
+) -> Result<(), String> {
+    if options.source.is_some() {
+        return Ok(());
+    }
+
+    let (year, day) = (solution.year(), solution.day());
+    let log = SubmissionLog::load(&submissions_path(year, day)).map_err(|err| err.to_string())?;
+    for (part, answer) in results {
+        if let Some(reason) = log.known_wrong(*part, answer) {
+            eprintln!(
+                "WARNING: {} day {} part {} is KNOWN WRONG: {}",
+                year, day, part, reason
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn run(options: &Options) -> Result<(), String> {
+    if let Some(header) = options.format.header() {
+        println!("{}", header);
+    }
+
+    for solution in options.selected()? {
+        let input = options.read_input(solution)?;
+
+        let results = if options.format == Format::Human {
+            println!("====== {} Day {} ======", solution.year(), solution.day());
+            solution.run(&input, &options.parts)
+        } else {
+            solution.rows(&input, &options.parts).map(|rows| {
+                rows.into_iter()
+                    .map(|row| {
+                        println!("{}", options.format.row(&row));
+                        (row.part, row.answer)
+                    })
+                    .collect()
+            })
+        }
+        .map_err(|err| err.to_string())?;
+        warn_known_wrong(options, solution, &results)?;
+    }
+
+    Ok(())
+}
+
+fn bench(options: &Options) -> Result<(), String> {
+    for solution in options.selected()? {
+        let input = options.read_input(solution)?;
+        let report = solution
+            .bench(&input, options.iterations)
+            .map_err(|err| err.to_string())?;
+
+        if options.json {
+            println!("{}", report.to_json());
+        } else {
+            println!(
+                "====== {} Day {} ({} iterations) ======",
+                solution.year(),
+                report.day,
+                report.iterations
+            );
+            print!("{}", report);
+        }
+    }
+
+    Ok(())
+}
+
+fn alloc(options: &Options) -> Result<(), String> {
+    if !memory::ENABLED {
+        return Err(
+            "allocations are not counted in this build, rebuild with --features alloc-stats"
+                .to_owned(),
+        );
+    }
+
+    for solution in options.selected()? {
+        let input = options.read_input(solution)?;
+        let report = solution.allocs(&input).map_err(|err| err.to_string())?;
+
+        if options.json {
+            println!("{}", report.to_json());
+        } else {
+            println!("====== {} Day {} ======", solution.year(), report.day);
+            print!("{}", report);
+        }
+    }
+
+    Ok(())
+}
+
+fn verify(options: &Options) -> Result<(), String> {
+    let mut failures = 0;
+
+    for solution in options.selected()? {
+        let day = solution.day();
+        let path = answers_path(solution.year(), day);
+        let answers = Answers::load(&path).map_err(|err| err.to_string())?;
+
+        let results = options.read_input(solution).and_then(|input| {
+            solution
+                .solve(&input, &options.parts)
+                .map_err(|err| err.to_string())
+        });
+        let results = match results {
+            Ok(results) => results,
+            Err(err) => {
+                println!("Day {}: FAILED, {}", day, err);
+                failures += 1;
+                continue;
+            }
+        };
+
+        for (part, answer) in results {
+            match answers.check(part, &answer) {
+                Verdict::Correct => println!("Day {} part {}: ok", day, part),
+                Verdict::Wrong => {
+                    println!("Day {} part {}: REGRESSION, got {}", day, part, answer);
+                    failures += 1;
+                }
+                Verdict::Missing => {
+                    println!(
+                        "Day {} part {}: MISSING answer in {}, got {}",
+                        day,
+                        part,
+                        path.display(),
+                        answer
+                    );
+                    failures += 1;
+                }
+            }
+        }
+    }
+
+    if failures > 0 {
+        return Err(format!("{} verification failure(s)", failures));
+    }
+
+    Ok(())
+}
+
+fn record(options: &Options) -> Result<(), String> {
+    let day = options.day.ok_or("record needs a DAY")?;
+    let solution = options.selected()?[0];
+    let path = answers_path(solution.year(), day);
+
+    let mut answers = Answers::load(&path).map_err(|err| err.to_string())?;
+    let input = options.read_input(solution)?;
+    let results = solution
+        .solve(&input, &options.parts)
+        .map_err(|err| err.to_string())?;
+    warn_known_wrong(options, solution, &results)?;
+
+    for (part, answer) in results {
+        let expected = if options.plain {
+            Expected::Plain(answer.clone())
+        } else {
+            Expected::hashed(&answer)
+        };
+        answers.set(part, expected);
+        println!("Day {} part {}: recorded {}", day, part, answer);
+    }
+
+    answers
+        .save(&path)
+        .map_err(|err| format!("could not write {}: {}", path.display(), err))
+}
+
+fn generate(options: &Options) -> Result<(), String> {
+    let day = options.day.ok_or("generate needs a DAY")?;
+    let solution = options.selected()?[0];
+
+    let input = solution
+        .generate(&mut Rng::new(options.seed), options.size)
+        .ok_or(format!("no generator for day {}", day))?;
+    print!("{}", input);
+
+    Ok(())
+}
+
+fn animate(options: &Options) -> Result<(), String> {
+    let day = options.day.ok_or("animate needs a DAY")?;
+    let solution = options.selected()?[0];
+    let input = options.read_input(solution)?;
+
+    let frames = solution
+        .frames(&input, options.parts[0])
+        .map_err(|err| err.to_string())?
+        .ok_or(format!("no animation for day {}", day))?;
+
+    let Some(dir) = &options.output else {
+        return player::play(frames, Duration::from_secs(1) / options.fps)
+            .map_err(|err| err.to_string());
+    };
+
+    fs::create_dir_all(dir)
+        .map_err(|err| format!("could not create {}: {}", dir.display(), err))?;
+    let mut written = 0;
+    for (i, frame) in frames.enumerate() {
+        let path = dir.join(format!("frame-{:05}.png", i));
+        frame
+            .to_image()
+            .scaled(options.scale)
+            .save(&path)
+            .map_err(|err| format!("could not write {}: {}", path.display(), err))?;
+        written += 1;
+    }
+    println!("Wrote {} frames to {}", written, dir.display());
+
+    Ok(())
+}
+
+fn gif(options: &Options) -> Result<(), String> {
+    let day = options.day.ok_or("gif needs a DAY")?;
+    let solution = options.selected()?[0];
+    let input = options.read_input(solution)?;
+    let path = options
+        .output
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(format!("day{}.gif", day)));
+
+    let frames = solution
+        .frames(&input, options.parts[0])
+        .map_err(|err| err.to_string())?
+        .ok_or(format!("no animation for day {}", day))?;
+    let window = frames
+        .enumerate()
+        .skip(options.from)
+        .take_while(|&(i, _)| options.to.is_none_or(|to| i <= to));
+
+    let write_error = |err: std::io::Error| format!("could not write {}: {}", path.display(), err);
+    let delay = (options.delay / 10).max(1);
+    let mut gif = None;
+    let mut add = |frame: &Frame| {
+        let image = frame.to_image().scaled(options.scale);
+        let gif = match &mut gif {
+            Some(gif) => gif,
+            None => {
+                let file = File::create(&path).map_err(write_error)?;
+                gif.insert(
+                    GifWriter::new(BufWriter::new(file), image.width(), image.height())
+                        .map_err(write_error)?,
+                )
+            }
+        };
+
+        gif.add_frame(&image, delay).map_err(write_error)
+    };
+
+    let (mut written, mut skipped, mut capped) = (0, None, false);
+    for (i, frame) in window {
+        if !(i - options.from).is_multiple_of(options.every) {
+            skipped = Some(frame);
+            continue;
+        }
+        if written == options.max_frames {
+            capped = true;
+            break;
+        }
+        add(&frame)?;
+        (written, skipped) = (written + 1, None);
+    }
+    if let Some(last) = skipped.filter(|_| written < options.max_frames) {
+        add(&last)?;
+        written += 1;
+    }
+
+    gif.ok_or("no frames in that window")?
+        .finish()
+        .map_err(write_error)?;
+    println!("Wrote {} frames to {}", written, path.display());
+    if capped {
+        println!("Stopped at --max-frames {}", options.max_frames);
+    }
+
+    Ok(())
+}
+
+fn image(options: &Options) -> Result<(), String> {
+    let day = options.day.ok_or("image needs a DAY")?;
+    let solution = options.selected()?[0];
+    let input = options.read_input(solution)?;
+    let path = options
+        .output
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(format!("day{}.png", day)));
+
+    let image = solution
+        .image(&input, options.parts[0])
+        .map_err(|err| err.to_string())?
+        .ok_or(format!("no image for day {}", day))?;
+    image
+        .scaled(options.scale)
+        .save(&path)
+        .map_err(|err| format!("could not write {}: {}", path.display(), err))?;
+    println!("Wrote {}", path.display());
+
+    Ok(())
+}
+
+fn new(options: &Options) -> Result<(), String> {
+    let day = options.day.ok_or("new needs a DAY")?;
+
+    scaffold::new_day(workspace_root(), options.year()?, day)
+}
+
+fn fetch(options: &Options) -> Result<(), String> {
+    let day = options.day.ok_or("fetch needs a DAY")?;
+    let year = options.year()?;
+    let path = day_dir(year, day, workspace_root()).join("input.txt");
+
+    if path.exists() {
+        println!("{} already exists, not fetching it again", path.display());
+        return Ok(());
+    }
+
+    let input = Site::from_env(workspace_root(), year)?.fetch_input(day)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|err| format!("could not create {}: {}", dir.display(), err))?;
+    }
+    fs::write(&path, input)
+        .map_err(|err| format!("could not write {}: {}", path.display(), err))?;
+    println!("Fetched {}", path.display());
+
+    Ok(())
+}
+
+fn submit(options: &Options) -> Result<(), String> {
+    let (Some(day), [part, answer]) = (options.day, &options.rest[..]) else {
+        return Err("submit needs a DAY, a PART and an ANSWER".to_owned());
+    };
+    let part: Part = part.parse()?;
+    let year = options.year()?;
+    let log_path = submissions_path(year, day);
+    let mut log = SubmissionLog::load(&log_path).map_err(|err| err.to_string())?;
+
+    if let Some(reason) = log.known_wrong(part, answer) {
+        return Err(format!("not submitting, {}", reason));
+    }
+
+    let outcome = Site::from_env(workspace_root(), year)?.submit(day, part, answer)?;
+    println!(
+        "{} day {} part {} ({}): {}",
+        year, day, part, answer, outcome
+    );
+
+    if let Some(feedback) = outcome.feedback() {
+        log.append(&log_path, Submission::new(part, feedback, answer))
+            .map_err(|err| format!("could not write {}: {}", log_path.display(), err))?;
+    }
+
+    if outcome == Outcome::Correct {
+        let path = answers_path(year, day);
+        let mut answers = Answers::load(&path).map_err(|err| err.to_string())?;
+        answers.set(part, Expected::hashed(answer));
+        answers
+            .save(&path)
+            .map_err(|err| format!("could not write {}: {}", path.display(), err))?;
+    }
+
+    Ok(())
+}
+
+fn mock_server(options: &Options) -> Result<(), String> {
+    let listener = TcpListener::bind(("127.0.0.1", options.port))
+        .map_err(|err| format!("could not listen on port {}: {}", options.port, err))?;
+    let fixtures = workspace_root().join("aoc/fixtures");
+    println!(
+        "Serving {} on http://127.0.0.1:{} (point ${} at it; any ${} is accepted)",
+        fixtures.display(),
+        options.port,
+        URL_VAR,
+        SESSION_VAR
+    );
+
+    mock::MockSite::new(fixtures, Duration::from_secs(60))
+        .serve(listener)
+        .map_err(|err| err.to_string())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let (command, positionals): (Command, usize) = match args.first().map(String::as_str) {
+        Some("run") => (run, 0),
+        Some("bench") => (bench, 0),
+        Some("alloc") => (alloc, 0),
+        Some("verify") => (verify, 0),
+        Some("record") => (record, 0),
+        Some("generate") => (generate, 0),
+        Some("animate") => (animate, 0),
+        Some("image") => (image, 0),
+        Some("gif") => (gif, 0),
+        Some("new") => (new, 0),
+        Some("fetch") => (fetch, 0),
+        Some("submit") => (submit, 2),
+        Some("mock-server") => (mock_server, 0),
+        Some("help" | "--help" | "-h") | None => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Some(other) => {
+            eprintln!("error: unknown command {:?}", other);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Err(err) = Options::parse(&args[1..], positionals).and_then(|options| command(&options))
+    {
+        eprintln!("error: {}", err);
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
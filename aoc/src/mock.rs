@@ -0,0 +1,307 @@
+use std::collections::HashSet;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use common::{Answers, Expected, Part};
+
+const NOT_FOUND: &str = "404 Not Found\n";
+const WRONG_LEVEL: &str =
+    "You don't seem to be solving the right level.  Did you already complete it?";
+
+/// A stand-in for the puzzle site, serving `fixtures/YEAR/dayN/input.txt` and judging answers
+/// against `fixtures/YEAR/dayN/answers.txt`. Any non-empty session cookie is accepted.
+pub struct MockSite {
+    fixtures: PathBuf,
+    cooldown: Duration,
+    solved: HashSet<(u16, u8, Part)>,
+    last_wrong: Option<Instant>,
+}
+
+struct Request {
+    method: String,
+    path: String,
+    session: Option<String>,
+    body: String,
+}
+
+fn read_request(stream: &mut TcpStream) -> io::Result<Request> {
+    let mut reader = BufReader::new(stream);
+
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut parts = line.split_whitespace();
+    let (method, path) = (parts.next().unwrap_or(""), parts.next().unwrap_or(""));
+    let (method, path) = (method.to_owned(), path.to_owned());
+
+    let (mut session, mut length) = (None, 0);
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header)?;
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+
+        let Some((name, value)) = header.split_once(": ") else {
+            continue;
+        };
+        match name.to_ascii_lowercase().as_str() {
+            "content-length" => length = value.parse().unwrap_or(0),
+            "cookie" => {
+                session = value
+                    .split("; ")
+                    .find_map(|cookie| cookie.strip_prefix("session="))
+                    .filter(|session| !session.is_empty())
+                    .map(str::to_owned);
+            }
+            _ => {}
+        }
+    }
+
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+
+    Ok(Request {
+        method,
+        path,
+        session,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
+
+/// Undoes a form body's percent-encoding, with `+` for a space.
+fn form_decode(value: &str) -> String {
+    let (raw, mut bytes, mut i) = (value.as_bytes(), Vec::new(), 0);
+    while i < raw.len() {
+        let hex = raw
+            .get(i + 1..i + 3)
+            .filter(|hex| hex.iter().all(u8::is_ascii_hexdigit));
+        match (raw[i], hex) {
+            (b'%', Some(hex)) => {
+                bytes.push(u8::from_str_radix(std::str::from_utf8(hex).unwrap(), 16).unwrap());
+                i += 3;
+                continue;
+            }
+            (b'+', _) => bytes.push(b' '),
+            (byte, _) => bytes.push(byte),
+        }
+        i += 1;
+    }
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+fn page(text: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>\n",
+        text
+    )
+}
+
+fn format_wait(left: Duration) -> String {
+    match left.as_secs() {
+        seconds if seconds >= 60 => format!("{}m {}s", seconds / 60, seconds % 60),
+        seconds => format!("{}s", seconds),
+    }
+}
+
+impl MockSite {
+    pub fn new(fixtures: PathBuf, cooldown: Duration) -> Self {
+        Self {
+            fixtures,
+            cooldown,
+            solved: HashSet::new(),
+            last_wrong: None,
+        }
+    }
+
+    /// Answers requests one at a time, forever.
+    pub fn serve(&mut self, listener: TcpListener) -> io::Result<()> {
+        for stream in listener.incoming() {
+            let mut stream = stream?;
+            let (status, body) = match read_request(&mut stream) {
+                Ok(request) => self.handle(&request),
+                Err(_) => (400, "Bad request\n".to_owned()),
+            };
+
+            let reason = match status {
+                200 => "OK",
+                400 => "Bad Request",
+                _ => "Not Found",
+            };
+            // A client that hangs up early is its own problem.
+            let _ = write!(
+                stream,
+                "HTTP/1.1 {} {}\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                reason,
+                body.len(),
+                body
+            );
+        }
+
+        Ok(())
+    }
+
+    fn handle(&mut self, request: &Request) -> (u16, String) {
+        let segments: Vec<&str> = request.path.trim_matches('/').split('/').collect();
+        let (year, day, endpoint) = match segments[..] {
+            [year, "day", day, endpoint] => match (year.parse::<u16>(), day.parse::<u8>()) {
+                (Ok(year), Ok(day)) => (year, day, endpoint),
+                _ => return (404, NOT_FOUND.to_owned()),
+            },
+            _ => return (404, NOT_FOUND.to_owned()),
+        };
+        let dir = self
+            .fixtures
+            .join(year.to_string())
+            .join(format!("day{}", day));
+
+        if request.session.is_none() {
+            return (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"
+                    .to_owned(),
+            );
+        }
+
+        match (request.method.as_str(), endpoint) {
+            ("GET", "input") => match fs::read_to_string(dir.join("input.txt")) {
+                Ok(input) => (200, input),
+                Err(_) => (404, NOT_FOUND.to_owned()),
+            },
+            ("POST", "answer") => match Answers::load(&dir.join("answers.txt")) {
+                Ok(answers) => (200, page(&self.judge(year, day, &answers, &request.body))),
+                Err(_) => (404, NOT_FOUND.to_owned()),
+            },
+            _ => (404, NOT_FOUND.to_owned()),
+        }
+    }
+
+    fn judge(&mut self, year: u16, day: u8, answers: &Answers, form: &str) -> String {
+        let field = |name: &str| {
+            form.split('&')
+                .find_map(|pair| pair.strip_prefix(name)?.strip_prefix('='))
+                .map(form_decode)
+                .unwrap_or_default()
+        };
+        let (level, answer) = (field("level"), field("answer"));
+
+        if let Some(left) = self
+            .last_wrong
+            .and_then(|at| self.cooldown.checked_sub(at.elapsed()))
+        {
+            return format!(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have {} left to wait.",
+                format_wait(left)
+            );
+        }
+
+        let (part, unlocked) = match level.as_str() {
+            "1" => (Part::One, true),
+            "2" => (Part::Two, self.solved.contains(&(year, day, Part::One))),
+            _ => return WRONG_LEVEL.to_owned(),
+        };
+        let expected = match answers.get(part) {
+            Some(expected) if unlocked && !self.solved.contains(&(year, day, part)) => expected,
+            _ => return WRONG_LEVEL.to_owned(),
+        };
+
+        if expected.matches(&answer) {
+            self.solved.insert((year, day, part));
+            return "That's the right answer!  You are <em>one gold star</em> closer.".to_owned();
+        }
+
+        self.last_wrong = Some(Instant::now());
+        let hint = match expected {
+            Expected::Plain(expected) => match (answer.parse::<i128>(), expected.parse::<i128>()) {
+                (Ok(answer), Ok(expected)) if answer > expected => "; your answer is too high",
+                (Ok(answer), Ok(expected)) if answer < expected => "; your answer is too low",
+                _ => "",
+            },
+            Expected::Hashed { .. } => "",
+        };
+
+        format!(
+            "That's not the right answer{}.  If you're stuck, make sure you're using the full input data.",
+            hint
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{form_encode, Outcome, Site};
+    use std::path::Path;
+    use std::thread;
+
+    fn start(cooldown: Duration) -> Site {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures");
+        thread::spawn(move || MockSite::new(fixtures, cooldown).serve(listener));
+
+        Site {
+            url,
+            year: 2024,
+            session: "test".to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_fetch() {
+        let site = start(Duration::ZERO);
+
+        assert!(site.fetch_input(1).unwrap().starts_with("3   4\n"));
+        assert!(site.fetch_input(25).is_err());
+        assert!(Site {
+            year: 2023,
+            ..site.clone()
+        }
+        .fetch_input(1)
+        .is_err());
+
+        let anonymous = Site {
+            session: String::new(),
+            ..site
+        };
+        assert!(anonymous.fetch_input(1).unwrap_err().contains("400"));
+    }
+
+    #[test]
+    fn test_submit() {
+        let site = start(Duration::ZERO);
+
+        assert_eq!(site.submit(1, Part::Two, "31"), Ok(Outcome::WrongLevel));
+        assert_eq!(site.submit(1, Part::One, "12"), Ok(Outcome::TooHigh));
+        assert_eq!(site.submit(1, Part::One, "10"), Ok(Outcome::TooLow));
+        assert_eq!(site.submit(1, Part::One, "11&x=1"), Ok(Outcome::Wrong));
+        assert_eq!(site.submit(1, Part::One, "11"), Ok(Outcome::Correct));
+        assert_eq!(site.submit(1, Part::One, "11"), Ok(Outcome::WrongLevel));
+        assert_eq!(site.submit(1, Part::Two, "31"), Ok(Outcome::Correct));
+    }
+
+    #[test]
+    fn test_form_encoding() {
+        let answer = "a b&answer=c+100%";
+
+        assert_eq!(form_decode(&form_encode(answer)), answer);
+        assert_eq!(form_decode("a+b%2"), "a b%2");
+    }
+
+    #[test]
+    fn test_cooldown() {
+        let site = start(Duration::from_secs(90));
+
+        assert_eq!(site.submit(1, Part::One, "abc"), Ok(Outcome::Wrong));
+        match site.submit(1, Part::One, "11") {
+            Ok(Outcome::Wait(left)) => assert!(left > Duration::from_secs(80)),
+            other => panic!("expected to wait, got {:?}", other),
+        }
+    }
+}
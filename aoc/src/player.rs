@@ -0,0 +1,154 @@
+use std::io::{self, IsTerminal, Read, Write};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::Duration;
+
+use common::{Frame, Frames};
+
+const MIN_DELAY: Duration = Duration::from_millis(1);
+const MAX_DELAY: Duration = Duration::from_secs(2);
+
+/// Puts the terminal in no-echo, key-at-a-time mode with `stty`, and puts it back when dropped.
+struct RawMode {
+    saved: String,
+}
+
+impl RawMode {
+    fn enter() -> Option<Self> {
+        let stty = |args: &[&str]| {
+            Command::new("stty")
+                .args(args)
+                .stdin(Stdio::inherit())
+                .stderr(Stdio::null())
+                .output()
+                .ok()
+                .filter(|output| output.status.success())
+        };
+
+        let saved = String::from_utf8(stty(&["-g"])?.stdout).ok()?;
+        stty(&["-icanon", "-echo", "min", "1"])?;
+
+        Some(Self {
+            saved: saved.trim().to_owned(),
+        })
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = Command::new("stty")
+            .arg(&self.saved)
+            .stdin(Stdio::inherit())
+            .status();
+    }
+}
+
+fn keys() -> Receiver<u8> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for byte in io::stdin().lock().bytes() {
+            if byte.ok().is_none_or(|byte| sender.send(byte).is_err()) {
+                break;
+            }
+        }
+    });
+
+    receiver
+}
+
+fn draw(out: &mut impl Write, frame: &Frame, status: &str) -> io::Result<()> {
+    write!(
+        out,
+        "\x1b[H{}\x1b[J{}\n{}",
+        frame.render(true),
+        frame.caption,
+        status
+    )?;
+    out.flush()
+}
+
+/// Shows `frames` in place, one every `delay`. Space pauses, `n` steps one frame (and pauses),
+/// `+` and `-` change speed, `q` quits. When stdout is not a terminal, the frames are printed
+/// one after the other without colours or waiting.
+pub fn play(mut frames: Frames, delay: Duration) -> io::Result<()> {
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+
+    if !stdout.is_terminal() {
+        for frame in frames {
+            write!(out, "{}{}\n\n", frame.render(false), frame.caption)?;
+        }
+        return Ok(());
+    }
+
+    let raw_mode = if io::stdin().is_terminal() {
+        RawMode::enter()
+    } else {
+        None
+    };
+    let keys = raw_mode.as_ref().map(|_| keys());
+
+    write!(out, "\x1b[?1049h\x1b[?25l")?;
+    let (mut delay, mut paused, mut shown) = (delay, false, 0);
+    let mut frame = frames.next();
+
+    let result = (|| {
+        while let Some(current) = &frame {
+            let status = format!(
+                "frame {}, {:?} per frame{}  [space] pause  [n] step  [+/-] speed  [q] quit",
+                shown,
+                delay,
+                if paused { ", PAUSED" } else { "" }
+            );
+            draw(&mut out, current, &status)?;
+
+            let key = match &keys {
+                Some(keys) if paused => match keys.recv() {
+                    Ok(key) => Some(key),
+                    Err(_) => break,
+                },
+                Some(keys) => keys.recv_timeout(delay).ok(),
+                None => {
+                    thread::sleep(delay);
+                    None
+                }
+            };
+
+            match key {
+                Some(b'q') => break,
+                Some(b' ') => paused = !paused,
+                Some(b'+' | b'=') => delay = (delay / 2).max(MIN_DELAY),
+                Some(b'-') => delay = (delay * 2).min(MAX_DELAY),
+                Some(b'n') => {
+                    paused = true;
+                    if let Some(next) = frames.next() {
+                        frame = Some(next);
+                        shown += 1;
+                    }
+                }
+                Some(_) => {}
+                None if paused => {}
+                None => match frames.next() {
+                    Some(next) => {
+                        frame = Some(next);
+                        shown += 1;
+                    }
+                    // Stay on the last frame until asked to quit.
+                    None if keys.is_some() => paused = true,
+                    None => break,
+                },
+            }
+        }
+
+        Ok(())
+    })();
+
+    write!(out, "\x1b[?25h\x1b[?1049l")?;
+    if let Some(last) = &frame {
+        writeln!(out, "{}", last.caption)?;
+    }
+    drop(raw_mode);
+
+    result
+}
@@ -0,0 +1,368 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const CARGO_TEMPLATE: &str = include_str!("../template/Cargo.toml.in");
+const LIB_TEMPLATE: &str = include_str!("../template/lib.rs.in");
+const BUILD_TEMPLATE: &str = include_str!("../template/build.rs.in");
+const EXAMPLE_TEMPLATE: &str = include_str!("../template/example.txt.in");
+const YEAR_CARGO_TEMPLATE: &str = include_str!("../template/year-Cargo.toml.in");
+const YEAR_LIB_TEMPLATE: &str = include_str!("../template/year-lib.rs.in");
+
+/// Lists of registrations longer than this are laid out one item per line, as rustfmt would.
+const MAX_WIDTH: usize = 100;
+
+fn render(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string())
+}
+
+/// The number after the first `prefix` in `line`, e.g. 12 for `    &day12::Day12,` and `day`.
+fn number_after<T: std::str::FromStr>(line: &str, prefix: &str) -> Option<T> {
+    let rest = &line[line.find(prefix)? + prefix.len()..];
+    let end = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+
+    rest[..end].parse().ok()
+}
+
+fn day_key(line: &str) -> Option<u8> {
+    number_after(line, "day")
+}
+
+fn year_key(line: &str) -> Option<u16> {
+    number_after(line, "aoc")
+}
+
+/// Workspace members sort by year, each year's crate just before its days.
+fn member_key(line: &str) -> Option<(u16, Option<u8>)> {
+    let member = line.trim().trim_end_matches(',').trim_matches('"');
+    let (year, day) = match member.split_once('/') {
+        Some((year, day)) => (year, Some(day_key(day)?)),
+        None => (member, None),
+    };
+
+    Some((year.parse().ok()?, day))
+}
+
+/// Inserts `line` among the keyed lines between the `open` line and the `close` line (or the
+/// end of the file), keeping them sorted.
+fn insert_sorted<K: Ord>(
+    text: &str,
+    open: &str,
+    close: &str,
+    line: &str,
+    key: impl Fn(&str) -> Option<K>,
+) -> Result<String, String> {
+    let new = key(line).ok_or(format!("{:?} cannot be registered", line))?;
+    let mut lines: Vec<&str> = text.lines().collect();
+
+    let start = lines
+        .iter()
+        .position(|candidate| candidate.trim() == open)
+        .ok_or(format!("could not find {:?}", open))?
+        + 1;
+    let end = lines[start..]
+        .iter()
+        .position(|candidate| candidate.trim() == close)
+        .map_or(lines.len(), |offset| start + offset);
+
+    let mut at = end;
+    for (index, existing) in lines.iter().enumerate().take(end).skip(start) {
+        match key(existing) {
+            Some(existing) if existing == new => {
+                return Err(format!("{} is already registered", line.trim()))
+            }
+            Some(existing) if existing > new => {
+                at = index;
+                break;
+            }
+            Some(_) => at = index + 1,
+            None => {}
+        }
+    }
+
+    lines.insert(at, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Inserts `item` into the Rust array literal that starts right after `open`, keeping the items
+/// sorted and the array on one line only while it fits.
+fn insert_item<K: Ord>(
+    text: &str,
+    open: &str,
+    item: &str,
+    key: impl Fn(&str) -> Option<K>,
+) -> Result<String, String> {
+    let new = key(item).ok_or(format!("{:?} cannot be registered", item))?;
+    let start = text
+        .find(open)
+        .ok_or(format!("could not find {:?}", open))?
+        + open.len();
+    let end = start + text[start..].find(']').ok_or("unterminated list")?;
+
+    let mut items: Vec<&str> = text[start..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|existing| !existing.is_empty())
+        .collect();
+    if items
+        .iter()
+        .any(|existing| key(existing).as_ref() == Some(&new))
+    {
+        return Err(format!("{} is already registered", item));
+    }
+    let at = items
+        .iter()
+        .position(|existing| key(existing).is_some_and(|existing| existing > new))
+        .unwrap_or(items.len());
+    items.insert(at, item);
+
+    let line_start = text[..start].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = text[end..].find('\n').map_or(text.len(), |i| end + i);
+    let (head, tail) = (&text[line_start..start], &text[end + 1..line_end]);
+    let indent = &head[..head.len() - head.trim_start().len()];
+
+    let mut list = format!("{}{}]{}", head, items.join(", "), tail);
+    if list.len() > MAX_WIDTH {
+        list = format!("{}\n", head);
+        for item in items {
+            list += &format!("{}    {},\n", indent, item);
+        }
+        list += &format!("{}]{}", indent, tail);
+    }
+
+    Ok(format!(
+        "{}{}{}",
+        &text[..line_start],
+        list,
+        &text[line_end..]
+    ))
+}
+
+fn create(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    fs::write(path, contents)
+}
+
+/// Creates `YEAR/dayN/` from the template and registers it with its year's crate. A year's first
+/// day also creates that crate and registers it with the workspace and the `aoc` binary.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<(), String> {
+    let year_dir = root.join(year.to_string());
+    let dir = year_dir.join(format!("day{}", day));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+    let new_year = !year_dir.join("Cargo.toml").exists();
+
+    let read = |path: &PathBuf| {
+        fs::read_to_string(path)
+            .map_err(|err| format!("could not read {}: {}", path.display(), err))
+    };
+    let year_file = |name: &str, template: &str| {
+        let path = year_dir.join(name);
+        let text = if new_year {
+            render(template, year, day)
+        } else {
+            read(&path)?
+        };
+
+        Ok::<_, String>((path, text))
+    };
+    let in_file = |path: &PathBuf, err: String| format!("{}: {}", path.display(), err);
+
+    // Every registration is checked before anything is written, so a clash leaves the tree as is.
+    let mut updated = vec![];
+
+    let path = root.join("Cargo.toml");
+    let mut members = read(&path)?;
+    if new_year {
+        members = insert_sorted(
+            &members,
+            "members = [",
+            "]",
+            &format!("    \"{}\",", year),
+            member_key,
+        )
+        .map_err(|err| in_file(&path, err))?;
+    }
+    let members = insert_sorted(
+        &members,
+        "members = [",
+        "]",
+        &format!("    \"{}/day{}\",", year, day),
+        member_key,
+    )
+    .map_err(|err| in_file(&path, err))?;
+    updated.push((path, members));
+
+    let (path, text) = year_file("Cargo.toml", YEAR_CARGO_TEMPLATE)?;
+    let dependency = format!(
+        "day{1} = {{ package = \"aoc{0}-day{1}\", path = \"day{1}\" }}",
+        year, day
+    );
+    let text = insert_sorted(&text, "[dependencies]", "", &dependency, day_key)
+        .map_err(|err| in_file(&path, err))?;
+    updated.push((path, text));
+
+    let (path, text) = year_file("src/lib.rs", YEAR_LIB_TEMPLATE)?;
+    let solution = format!("&day{0}::Day{0}", day);
+    let text =
+        insert_item(&text, "days: &[", &solution, day_key).map_err(|err| in_file(&path, err))?;
+    updated.push((path, text));
+
+    if new_year {
+        let path = root.join("aoc/Cargo.toml");
+        let dependency = format!("aoc{0} = {{ path = \"../{0}\" }}", year);
+        let text = insert_sorted(&read(&path)?, "[dependencies]", "", &dependency, year_key)
+            .map_err(|err| in_file(&path, err))?;
+        updated.push((path, text));
+
+        let path = root.join("aoc/src/years.rs");
+        let registration = format!("aoc{}::YEAR", year);
+        let text = insert_item(
+            &read(&path)?,
+            "pub const YEARS: &[Year] = &[",
+            &registration,
+            year_key,
+        )
+        .map_err(|err| in_file(&path, err))?;
+        updated.push((path, text));
+    }
+
+    for (path, text) in updated {
+        let existed = path.exists();
+        create(&path, &text)
+            .map_err(|err| format!("could not write {}: {}", path.display(), err))?;
+        if !existed {
+            println!("created {}", path.display());
+        }
+    }
+
+    for (path, contents) in [
+        (dir.join("Cargo.toml"), render(CARGO_TEMPLATE, year, day)),
+        (dir.join("build.rs"), BUILD_TEMPLATE.to_owned()),
+        (dir.join("src/lib.rs"), render(LIB_TEMPLATE, year, day)),
+        (
+            dir.join("examples/example.txt"),
+            EXAMPLE_TEMPLATE.to_owned(),
+        ),
+        (dir.join("input.txt"), String::new()),
+    ] {
+        create(&path, &contents)
+            .map_err(|err| format!("could not write {}: {}", path.display(), err))?;
+        println!("created {}", path.display());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::process;
+
+    #[test]
+    fn test_insert_sorted() {
+        let members = "members = [\n    \"aoc\",\n    \"common\",\n    \"2024\",\n    \"2024/day2\",\n    \"2024/day10\",\n]\n";
+        let insert = |line| insert_sorted(members, "members = [", "]", line, member_key);
+
+        assert_eq!(
+            insert("    \"2024/day9\",").unwrap(),
+            "members = [\n    \"aoc\",\n    \"common\",\n    \"2024\",\n    \"2024/day2\",\n    \"2024/day9\",\n    \"2024/day10\",\n]\n"
+        );
+        assert_eq!(
+            insert("    \"2025\",").unwrap(),
+            "members = [\n    \"aoc\",\n    \"common\",\n    \"2024\",\n    \"2024/day2\",\n    \"2024/day10\",\n    \"2025\",\n]\n"
+        );
+        assert!(insert("    \"2024/day10\",").is_err());
+    }
+
+    #[test]
+    fn test_insert_at_end_of_file() {
+        let dependencies = "[dependencies]\ncommon = { path = \"../common\" }\n";
+
+        assert_eq!(
+            insert_sorted(
+                dependencies,
+                "[dependencies]",
+                "",
+                "day1 = { package = \"aoc2024-day1\", path = \"day1\" }",
+                day_key
+            )
+            .unwrap(),
+            "[dependencies]\ncommon = { path = \"../common\" }\nday1 = { package = \"aoc2024-day1\", path = \"day1\" }\n"
+        );
+    }
+
+    #[test]
+    fn test_insert_item() {
+        let years = "pub const YEARS: &[Year] = &[aoc2024::YEAR];\n\npub fn find() {}\n";
+        let open = "pub const YEARS: &[Year] = &[";
+
+        assert_eq!(
+            insert_item(years, open, "aoc2023::YEAR", year_key).unwrap(),
+            "pub const YEARS: &[Year] = &[aoc2023::YEAR, aoc2024::YEAR];\n\npub fn find() {}\n"
+        );
+        assert!(insert_item(years, open, "aoc2024::YEAR", year_key).is_err());
+
+        let mut days = "    days: &[],\n".to_owned();
+        for day in [3, 1, 12, 2, 10, 11, 4, 5] {
+            days =
+                insert_item(&days, "days: &[", &format!("&day{0}::Day{0}", day), day_key).unwrap();
+        }
+        assert_eq!(
+            days,
+            "    days: &[\n        &day1::Day1,\n        &day2::Day2,\n        &day3::Day3,\n        &day4::Day4,\n        &day5::Day5,\n        &day10::Day10,\n        &day11::Day11,\n        &day12::Day12,\n    ],\n"
+        );
+    }
+
+    #[test]
+    fn test_new_year() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", process::id()));
+        let _ = fs::remove_dir_all(&root);
+        create(
+            &root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"common\",\n    \"2024\",\n    \"2024/day1\",\n]\n",
+        )
+        .unwrap();
+        create(
+            &root.join("aoc/Cargo.toml"),
+            "[dependencies]\naoc2024 = { path = \"../2024\" }\n",
+        )
+        .unwrap();
+        create(
+            &root.join("aoc/src/years.rs"),
+            "pub const YEARS: &[Year] = &[aoc2024::YEAR];\n",
+        )
+        .unwrap();
+
+        new_day(&root, 2025, 3).unwrap();
+        new_day(&root, 2025, 1).unwrap();
+        assert!(new_day(&root, 2025, 1).is_err());
+
+        let read = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+        assert!(read("Cargo.toml").ends_with(
+            "    \"2024/day1\",\n    \"2025\",\n    \"2025/day1\",\n    \"2025/day3\",\n]\n"
+        ));
+        assert!(read("aoc/Cargo.toml").ends_with("aoc2025 = { path = \"../2025\" }\n"));
+        assert_eq!(
+            read("aoc/src/years.rs"),
+            "pub const YEARS: &[Year] = &[aoc2024::YEAR, aoc2025::YEAR];\n"
+        );
+        assert!(read("2025/Cargo.toml").contains(
+            "day1 = { package = \"aoc2025-day1\", path = \"day1\" }\nday3 = { package = \"aoc2025-day3\", path = \"day3\" }\n"
+        ));
+        assert!(read("2025/src/lib.rs").contains("    days: &[&day1::Day1, &day3::Day3],\n"));
+        assert!(read("2025/day3/src/lib.rs").contains("const YEAR: u16 = 2025;"));
+        assert!(read("2025/day3/Cargo.toml").contains("name = \"aoc2025-day3\""));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,37 @@
+use common::Year;
+
+pub const YEARS: &[Year] = &[aoc2024::YEAR];
+
+pub fn find(year: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|y| y.year == year)
+}
+
+/// The most recent event with any solutions.
+pub fn latest() -> &'static Year {
+    YEARS.iter().max_by_key(|y| y.year).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Rng;
+
+    #[test]
+    fn test_generated_inputs_parse() {
+        for solution in YEARS.iter().flat_map(|year| year.days) {
+            for seed in 0..3 {
+                let input = solution.generate(&mut Rng::new(seed), 20).unwrap();
+
+                if let Err(err) = solution.solve(&input, &[]) {
+                    panic!(
+                        "{} day {} seed {}: {}",
+                        solution.year(),
+                        solution.day(),
+                        seed,
+                        err
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{Grid, Image, Rgb};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Default,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    Gray,
+}
+
+impl Color {
+    fn ansi(self) -> &'static str {
+        match self {
+            Color::Default => "\x1b[0m",
+            Color::Red => "\x1b[31m",
+            Color::Green => "\x1b[32m",
+            Color::Yellow => "\x1b[1;33m",
+            Color::Blue => "\x1b[34m",
+            Color::Magenta => "\x1b[35m",
+            Color::Cyan => "\x1b[36m",
+            Color::Gray => "\x1b[90m",
+        }
+    }
+
+    pub fn rgb(self) -> Rgb {
+        match self {
+            Color::Default => Rgb(229, 229, 229),
+            Color::Red => Rgb(205, 49, 49),
+            Color::Green => Rgb(13, 188, 121),
+            Color::Yellow => Rgb(245, 245, 67),
+            Color::Blue => Rgb(36, 114, 200),
+            Color::Magenta => Rgb(188, 63, 188),
+            Color::Cyan => Rgb(17, 168, 205),
+            Color::Gray => Rgb(40, 40, 40),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub symbol: char,
+    pub color: Color,
+}
+
+impl Cell {
+    pub const fn new(symbol: char, color: Color) -> Self {
+        Self { symbol, color }
+    }
+}
+
+/// One step of a simulation: what the grid looks like, and a line saying where we are.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub cells: Grid<Cell>,
+    pub caption: String,
+}
+
+/// A simulation replayed frame by frame, owning everything it needs.
+pub type Frames = Box<dyn Iterator<Item = Frame>>;
+
+impl Frame {
+    pub fn new(cells: Grid<Cell>, caption: impl Into<String>) -> Self {
+        Self {
+            cells,
+            caption: caption.into(),
+        }
+    }
+
+    /// The grid, one line per row, with ANSI colour codes only where the colour changes.
+    pub fn render(&self, color: bool) -> String {
+        let mut out = String::with_capacity(self.cells.rows() * (self.cells.cols() + 1));
+
+        for row in self.cells.iter_rows() {
+            let mut current = Color::Default;
+            for cell in row {
+                if color && cell.color != current {
+                    out.push_str(cell.color.ansi());
+                    current = cell.color;
+                }
+                out.push(cell.symbol);
+            }
+            if current != Color::Default {
+                out.push_str(Color::Default.ansi());
+            }
+            out.push('\n');
+        }
+
+        out
+    }
+
+    /// One pixel per cell in the cell's colour; symbols are lost.
+    pub fn to_image(&self) -> Image {
+        Image::from_grid(&self.cells, |cell| cell.color.rgb())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let wall = Cell::new('#', Color::Gray);
+        let robot = Cell::new('@', Color::Yellow);
+        let frame = Frame::new(Grid::new(2, 2, vec![wall, wall, robot, wall]), "step 1");
+
+        assert_eq!(frame.render(false), "##\n@#\n");
+        assert_eq!(
+            frame.render(true),
+            "\x1b[90m##\x1b[0m\n\x1b[1;33m@\x1b[90m#\x1b[0m\n"
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::fs;
+use std::hash::{BuildHasher, Hasher};
+use std::io;
+use std::path::Path;
+
+use crate::sha256::sha256_hex;
+use crate::{parse, ParseError, Part};
+
+/// An accepted answer, either as-is or salted and hashed so it can be committed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expected {
+    Plain(String),
+    Hashed { salt: String, digest: String },
+}
+
+impl Expected {
+    pub fn hashed(answer: &str) -> Self {
+        let salt = format!("{:016x}", RandomState::new().build_hasher().finish());
+        let digest = sha256_hex(format!("{}{}", salt, answer).as_bytes());
+
+        Expected::Hashed { salt, digest }
+    }
+
+    /// `raw`, a slice of `input`, as written in `answers.txt` and `submissions.txt`: the answer
+    /// itself or `sha256:SALT:DIGEST`.
+    pub fn parse(input: &str, raw: &str) -> Result<Self, ParseError> {
+        Ok(match raw.strip_prefix("sha256:") {
+            Some(hashed) => {
+                let (salt, digest) = parse::split_once(input, hashed, ":")?;
+                Expected::Hashed {
+                    salt: salt.to_owned(),
+                    digest: digest.to_owned(),
+                }
+            }
+            None => Expected::Plain(raw.to_owned()),
+        })
+    }
+
+    pub fn matches(&self, answer: &str) -> bool {
+        match self {
+            Expected::Plain(expected) => expected == answer,
+            Expected::Hashed { salt, digest } => {
+                sha256_hex(format!("{}{}", salt, answer).as_bytes()) == *digest
+            }
+        }
+    }
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Plain(answer) => write!(f, "{}", answer),
+            Expected::Hashed { salt, digest } => write!(f, "sha256:{}:{}", salt, digest),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    Missing,
+}
+
+/// The contents of a day's `answers.txt`: `part1 = ...` and `part2 = ...` lines, `#` comments.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    part1: Option<Expected>,
+    part2: Option<Expected>,
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut answers = Answers::default();
+
+        for line in parse::entries(input) {
+            let (key, value) = parse::split_once(input, line, "=")?;
+            let (key, value) = (key.trim(), value.trim());
+            let part = match key {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                _ => return Err(ParseError::at(input, key, "expected part1 or part2")),
+            };
+
+            let expected = Expected::parse(input, value)?;
+            answers.set(part, expected);
+        }
+
+        Ok(answers)
+    }
+
+    /// A missing file is the same as an empty one.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        parse::load(path, Self::parse)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, part: Part) -> Option<&Expected> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, expected: Expected) {
+        match part {
+            Part::One => self.part1 = Some(expected),
+            Part::Two => self.part2 = Some(expected),
+        }
+    }
+
+    pub fn check(&self, part: Part, answer: &str) -> Verdict {
+        match self.get(part) {
+            Some(expected) if expected.matches(answer) => Verdict::Correct,
+            Some(_) => Verdict::Wrong,
+            None => Verdict::Missing,
+        }
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for part in Part::ALL {
+            if let Some(expected) = self.get(part) {
+                writeln!(f, "part{} = {}", part, expected)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hashed() {
+        let expected = Expected::hashed("11387");
+
+        assert!(expected.matches("11387"));
+        assert!(!expected.matches("11386"));
+        assert_ne!(Expected::hashed("11387"), expected);
+    }
+
+    #[test]
+    fn test_parse_expected() {
+        let expected = Expected::hashed("11387");
+        let text = expected.to_string();
+
+        assert_eq!(Expected::parse(&text, &text), Ok(expected));
+        assert_eq!(
+            Expected::parse("3749", "3749"),
+            Ok(Expected::Plain("3749".to_owned()))
+        );
+        assert_eq!(
+            Expected::parse("sha256:x", "sha256:x").unwrap_err().text,
+            "x"
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut answers = Answers::default();
+        answers.set(Part::One, Expected::Plain("3749".to_owned()));
+        answers.set(Part::Two, Expected::hashed("11387"));
+
+        let parsed = Answers::parse(&answers.to_string()).unwrap();
+
+        assert_eq!(parsed, answers);
+        assert_eq!(parsed.check(Part::One, "3749"), Verdict::Correct);
+        assert_eq!(parsed.check(Part::Two, "4105723140243"), Verdict::Wrong);
+    }
+
+    #[test]
+    fn test_missing() {
+        let answers = Answers::parse("# only part 1 so far\npart1 = 143\n").unwrap();
+
+        assert_eq!(answers.check(Part::Two, "123"), Verdict::Missing);
+        assert_eq!(Answers::parse("part3 = 1").unwrap_err().text, "part3");
+    }
+}
@@ -0,0 +1,169 @@
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::{ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarise");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+
+        let mean = sorted.iter().sum::<Duration>() / sorted.len() as u32;
+        let variance = sorted
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / sorted.len() as f64;
+
+        Self {
+            min: sorted[0],
+            median,
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+
+    fn to_json(self) -> String {
+        format!(
+            "{{\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"stddev_ns\":{}}}",
+            self.min.as_nanos(),
+            self.median.as_nanos(),
+            self.mean.as_nanos(),
+            self.stddev.as_nanos()
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchReport {
+    pub year: u16,
+    pub day: u8,
+    pub iterations: usize,
+    pub process: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl BenchReport {
+    /// One JSON object per report, with every duration in nanoseconds.
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"day\":{},\"iterations\":{},\"process\":{},\"part1\":{},\"part2\":{},\"year\":{}}}",
+            self.day,
+            self.iterations,
+            self.process.to_json(),
+            self.part1.to_json(),
+            self.part2.to_json(),
+            self.year
+        )
+    }
+}
+
+impl fmt::Display for BenchReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<8}{:>12}{:>12}{:>12}{:>12}",
+            "", "min", "median", "mean", "stddev"
+        )?;
+        for (name, stats) in [
+            ("process", self.process),
+            ("part 1", self.part1),
+            ("part 2", self.part2),
+        ] {
+            writeln!(
+                f,
+                "{:<8}{:>12}{:>12}{:>12}{:>12}",
+                name,
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.mean),
+                format!("{:.2?}", stats.stddev)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = black_box(f());
+
+    (result, start.elapsed())
+}
+
+/// Times `process`, `part1` and `part2` separately, `iterations` times each.
+pub fn bench<S: Solution>(input: &str, iterations: usize) -> Result<BenchReport, ParseError> {
+    let iterations = iterations.max(1);
+    let mut samples = [vec![], vec![], vec![]];
+
+    for _ in 0..iterations {
+        let (processed, elapsed) = time(|| S::process(black_box(input)));
+        let processed = processed.map_err(|err| err.with_day(S::DAY))?;
+        samples[0].push(elapsed);
+
+        samples[1].push(time(|| S::part1(&processed).to_string()).1);
+        samples[2].push(time(|| S::part2(&processed).to_string()).1);
+    }
+
+    Ok(BenchReport {
+        year: S::YEAR,
+        day: S::DAY,
+        iterations,
+        process: Stats::from_samples(&samples[0]),
+        part1: Stats::from_samples(&samples[1]),
+        part2: Stats::from_samples(&samples[2]),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.stddev.as_micros(), 1118);
+    }
+
+    #[test]
+    fn test_report_json() {
+        let stats = Stats::from_samples(&[Duration::from_nanos(5)]);
+        let report = BenchReport {
+            year: 2024,
+            day: 3,
+            iterations: 1,
+            process: stats,
+            part1: stats,
+            part2: stats,
+        };
+
+        let json = report.to_json();
+        assert!(json.starts_with("{\"day\":3,\"iterations\":1,\"process\":{\"min_ns\":5,"));
+        assert!(json.ends_with(",\"year\":2024}"));
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::hash_map::{self, HashMap};
+use std::hash::Hash;
+use std::iter::Sum;
+use std::ops::{AddAssign, Mul};
+
+/// What a `Counter` counts in. Pick `u128` when counts can outgrow `u64`.
+pub trait Count: Copy + Ord + Default + AddAssign + Mul<Output = Self> + Sum {
+    const ONE: Self;
+}
+
+macro_rules! impl_count {
+    ($($t:ty),*) => {
+        $(
+            impl Count for $t {
+                const ONE: Self = 1;
+            }
+        )*
+    };
+}
+
+impl_count!(u32, u64, u128, usize);
+
+/// A multiset: how many times each key has been seen.
+#[derive(Debug, Clone)]
+pub struct Counter<K, C = u64> {
+    counts: HashMap<K, C>,
+}
+
+impl<K: Hash + Eq, C: Count> Counter<K, C> {
+    pub fn new() -> Self {
+        Self {
+            counts: HashMap::new(),
+        }
+    }
+
+    pub fn add(&mut self, key: K) {
+        self.add_n(key, C::ONE);
+    }
+
+    pub fn add_n(&mut self, key: K, n: C) {
+        *self.counts.entry(key).or_default() += n;
+    }
+
+    /// How many times `key` was seen; zero if never.
+    pub fn get(&self, key: &K) -> C {
+        self.counts.get(key).copied().unwrap_or_default()
+    }
+
+    /// Adds every count in `other` to this one.
+    pub fn merge(&mut self, other: Self) {
+        for (key, n) in other.counts {
+            self.add_n(key, n);
+        }
+    }
+
+    /// The number of distinct keys.
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// All the counts added up.
+    pub fn total(&self) -> C {
+        self.counts.values().copied().sum()
+    }
+
+    /// Each key with its count, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, C)> {
+        self.counts.iter().map(|(key, &n)| (key, n))
+    }
+
+    /// Every key, most common first, ties broken by the smaller key.
+    pub fn most_common(&self) -> Vec<(&K, C)>
+    where
+        K: Ord,
+    {
+        let mut counts: Vec<_> = self.iter().collect();
+        counts.sort_unstable_by(|(a, m), (b, n)| n.cmp(m).then_with(|| a.cmp(b)));
+
+        counts
+    }
+
+    /// Replaces each key by the keys `f` gives for it, each seen `weight` times as often as
+    /// the key it came from. Keys that come out more than once are added up.
+    pub fn expand<K2, I>(&self, f: impl Fn(&K) -> I) -> Counter<K2, C>
+    where
+        K2: Hash + Eq,
+        I: IntoIterator<Item = (K2, C)>,
+    {
+        let mut expanded = Counter::new();
+        for (key, &n) in &self.counts {
+            for (new_key, weight) in f(key) {
+                expanded.add_n(new_key, n * weight);
+            }
+        }
+
+        expanded
+    }
+}
+
+impl<K: Hash + Eq, C: Count> Default for Counter<K, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Hash + Eq, C: Count> FromIterator<K> for Counter<K, C> {
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        let mut counter = Self::new();
+        counter.extend(iter);
+
+        counter
+    }
+}
+
+impl<K: Hash + Eq, C: Count> Extend<K> for Counter<K, C> {
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        for key in iter {
+            self.add(key);
+        }
+    }
+}
+
+impl<K, C> IntoIterator for Counter<K, C> {
+    type Item = (K, C);
+    type IntoIter = hash_map::IntoIter<K, C>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.counts.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counts() {
+        let mut counter: Counter<char> = "mississippi".chars().collect();
+
+        assert_eq!(counter.get(&'s'), 4);
+        assert_eq!(counter.get(&'x'), 0);
+        assert_eq!((counter.len(), counter.total()), (4, 11));
+        assert_eq!(
+            counter.most_common(),
+            [(&'i', 4), (&'s', 4), (&'p', 2), (&'m', 1)]
+        );
+
+        counter.merge("spam".chars().collect());
+        counter.add_n('x', 3);
+        assert_eq!((counter.get(&'s'), counter.get(&'a')), (5, 1));
+        assert_eq!(counter.total(), 18);
+    }
+
+    #[test]
+    fn test_expand() {
+        // Each cell splits in two every step: 2^100 needs more than 64 bits.
+        let mut cells: Counter<u8, u128> = [0].into_iter().collect();
+        for _ in 0..100 {
+            cells = cells.expand(|&age| [(0, 1), (age.saturating_add(1), 1)]);
+        }
+
+        assert_eq!(cells.total(), 1 << 100);
+        assert_eq!(cells.get(&0), 1 << 99);
+        assert_eq!(cells.get(&100), 1);
+    }
+}
@@ -0,0 +1,98 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use crate::{Part, Rng, Solution};
+
+/// Removes chunks of `input` for as long as `fails` keeps holding: first whole blank-line
+/// separated blocks, then lines, then words, then single characters.
+pub fn shrink(input: &str, mut fails: impl FnMut(&str) -> bool) -> String {
+    let mut current = input.to_owned();
+
+    for separator in ["\n\n", "\n", " ", ""] {
+        'smaller: loop {
+            let units: Vec<String> = if separator.is_empty() {
+                current.chars().map(String::from).collect()
+            } else {
+                current.split(separator).map(str::to_owned).collect()
+            };
+
+            let mut chunk = units.len() / 2;
+            while chunk > 0 {
+                for start in (0..units.len()).step_by(chunk) {
+                    let mut candidate = units[..start].to_vec();
+                    candidate.extend_from_slice(&units[(start + chunk).min(units.len())..]);
+                    let candidate = candidate.join(separator);
+
+                    if fails(&candidate) {
+                        current = candidate;
+                        continue 'smaller;
+                    }
+                }
+                chunk /= 2;
+            }
+
+            break;
+        }
+    }
+
+    current
+}
+
+/// Compares `S`'s answer for `part` against a naive `reference` on `cases` generated inputs of
+/// the given size. The first disagreement, or panic in `S`, is shrunk to a minimal input and
+/// reported by panicking.
+pub fn compare<S: Solution, R: ToString>(
+    part: Part,
+    reference: impl Fn(&S::Input) -> R,
+    cases: u64,
+    size: usize,
+) {
+    // Candidates that no longer parse, or that the reference itself chokes on, prove nothing.
+    let disagreement = |input: &str| -> Option<(String, String)> {
+        let processed = S::process(input).ok()?;
+        let expected = catch_unwind(AssertUnwindSafe(|| reference(&processed).to_string())).ok()?;
+        let actual = catch_unwind(AssertUnwindSafe(|| match part {
+            Part::One => S::part1(&processed).to_string(),
+            Part::Two => S::part2(&processed).to_string(),
+        }))
+        .unwrap_or_else(|_| "a panic".to_owned());
+
+        (actual != expected).then_some((actual, expected))
+    };
+
+    for seed in 0..cases {
+        let input = S::generate(&mut Rng::new(seed), size)
+            .unwrap_or_else(|| panic!("day {} has no generator", S::DAY));
+
+        if disagreement(&input).is_some() {
+            let smallest = shrink(&input, |candidate| disagreement(candidate).is_some());
+            let (actual, expected) = disagreement(&smallest).unwrap();
+
+            panic!(
+                "day {} part {}, seed {}: got {}, reference says {}, for\n{}",
+                S::DAY,
+                part,
+                seed,
+                actual,
+                expected,
+                smallest
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shrink() {
+        let input = "1 2\n3 4\n\n5 6\n7 8\n";
+
+        assert_eq!(shrink(input, |candidate| candidate.contains('7')), "7");
+        assert_eq!(
+            shrink(input, |candidate| candidate.contains('3')
+                && candidate.contains('8')),
+            "38"
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::{parse, Answers, ParseError, Part, Solution};
+
+/// An `examples/*.txt` file: expected answers in the `answers.txt` format, a `---` line, then
+/// the puzzle input verbatim. `param NAME = VALUE` lines among the answers pass settings the
+/// example needs to the day, through `Solution::set_param`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example<'a> {
+    pub answers: Answers,
+    pub params: Vec<(&'a str, &'a str)>,
+    pub input: &'a str,
+}
+
+impl<'a> Example<'a> {
+    pub fn parse(text: &'a str) -> Result<Self, ParseError> {
+        let (mut offset, mut params, mut answers) = (0, vec![], String::new());
+        for line in text.split_inclusive('\n') {
+            if line.trim_end() == "---" {
+                return Ok(Self {
+                    answers: Answers::parse(&answers)?,
+                    params,
+                    input: &text[offset + line.len()..],
+                });
+            }
+
+            // Param lines are blanked rather than dropped so answer errors keep their line.
+            match line.trim().strip_prefix("param ") {
+                Some(param) => {
+                    let (name, value) = parse::split_once(text, param, "=")?;
+                    params.push((name.trim(), value.trim()));
+                    answers.push('\n');
+                }
+                None => answers.push_str(line),
+            }
+            offset += line.len();
+        }
+
+        Err(ParseError::at_end(
+            text,
+            "expected a \"---\" line before the input",
+        ))
+    }
+}
+
+/// Runs one example against `S`, panicking on any answer that does not match.
+pub fn check<S: Solution>(name: &str, text: &str) {
+    let example = Example::parse(text).unwrap_or_else(|err| panic!("{}: {}", name, err));
+    let mut input = S::process(example.input).unwrap_or_else(|err| panic!("{}: {}", name, err));
+    for (param, value) in &example.params {
+        S::set_param(&mut input, param, value)
+            .unwrap_or_else(|err| panic!("{}: param {}: {}", name, param, err));
+    }
+
+    for part in Part::ALL {
+        let Some(expected) = example.answers.get(part) else {
+            continue;
+        };
+        let answer = match part {
+            Part::One => S::part1(&input).to_string(),
+            Part::Two => S::part2(&input).to_string(),
+        };
+
+        assert!(
+            expected.matches(&answer),
+            "{} part {}: expected {}, got {}",
+            name,
+            part,
+            expected,
+            answer
+        );
+    }
+}
+
+fn test_name(path: &Path) -> String {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let name: String = stem
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("example_{}", name)
+    } else {
+        name
+    }
+}
+
+/// Build script entry point: writes `$OUT_DIR/examples.rs` with a `#[test]` per
+/// `examples/*.txt` file, for `example_tests!` to include. Examples without any expected answer
+/// are generated as ignored tests.
+pub fn generate() {
+    let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let dir = root.join("examples");
+    println!("cargo:rerun-if-changed={}", dir.display());
+
+    let mut paths: Vec<PathBuf> = fs::read_dir(&dir)
+        .unwrap_or_else(|err| panic!("could not read {}: {}", dir.display(), err))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .collect();
+    paths.sort();
+
+    let mut tests = String::new();
+    for path in paths {
+        let text = fs::read_to_string(&path)
+            .unwrap_or_else(|err| panic!("could not read {}: {}", path.display(), err));
+        let example =
+            Example::parse(&text).unwrap_or_else(|err| panic!("{}: {}", path.display(), err));
+
+        if Part::ALL
+            .iter()
+            .all(|&part| example.answers.get(part).is_none())
+        {
+            tests.push_str("#[ignore = \"no expected answers yet\"]\n");
+        }
+        tests.push_str(&format!(
+            "#[test]\nfn {}() {{\n    check::<Day>({:?}, include_str!({:?}));\n}}\n\n",
+            test_name(&path),
+            path.file_name().unwrap().to_string_lossy(),
+            path.to_string_lossy()
+        ));
+    }
+
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("examples.rs");
+    fs::write(&out, tests)
+        .unwrap_or_else(|err| panic!("could not write {}: {}", out.display(), err));
+}
+
+/// Declares the tests that `generate` wrote for the calling day, checked against `$solution`.
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ident) => {
+        #[cfg(test)]
+        mod examples {
+            use $crate::examples::check;
+
+            type Day = super::$solution;
+
+            include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_example() {
+        let example = Example::parse("part1 = 11\n# part 2 is unknown\n---\n3   4\n").unwrap();
+
+        assert_eq!(example.input, "3   4\n");
+        assert!(example.answers.get(Part::One).unwrap().matches("11"));
+        assert_eq!(example.answers.get(Part::Two), None);
+        assert!(example.params.is_empty());
+        assert!(Example::parse("part1 = 11\n3   4\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_params() {
+        let example = Example::parse("param size = 11,7\npart1 = 12\n---\np=0,4 v=3,-3\n").unwrap();
+
+        assert_eq!(example.params, [("size", "11,7")]);
+        assert!(example.answers.get(Part::One).unwrap().matches("12"));
+        assert_eq!(example.input, "p=0,4 v=3,-3\n");
+
+        let err = Example::parse("param size\npart3 = 1\n---\n").unwrap_err();
+        assert_eq!(err.line, 1);
+        let err = Example::parse("param size = 1\npart3 = 1\n---\n").unwrap_err();
+        assert_eq!(err.line, 2);
+    }
+
+    #[test]
+    fn test_name_is_an_identifier() {
+        assert_eq!(
+            test_name(Path::new("examples/larger-map.txt")),
+            "larger_map"
+        );
+        assert_eq!(test_name(Path::new("examples/2x2.txt")), "example_2x2");
+    }
+}
@@ -0,0 +1,279 @@
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+use crate::{Image, Rgb};
+
+const MAX_CODE_SIZE: u32 = 12;
+
+/// Writes an animated GIF89a one frame at a time, looping forever. Every frame has its own
+/// colour table, so each may use up to 256 colours.
+pub struct GifWriter<W: Write> {
+    out: W,
+    width: u16,
+    height: u16,
+}
+
+impl<W: Write> GifWriter<W> {
+    pub fn new(mut out: W, width: usize, height: usize) -> io::Result<Self> {
+        let (width, height) = (dimension(width)?, dimension(height)?);
+
+        out.write_all(b"GIF89a")?;
+        out.write_all(&width.to_le_bytes())?;
+        out.write_all(&height.to_le_bytes())?;
+        // No global colour table, background 0, square pixels.
+        out.write_all(&[0, 0, 0])?;
+        // NETSCAPE2.0 extension: loop forever.
+        out.write_all(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00")?;
+
+        Ok(Self { out, width, height })
+    }
+
+    /// Adds `image`, shown for `delay` hundredths of a second.
+    pub fn add_frame(&mut self, image: &Image, delay: u16) -> io::Result<()> {
+        if (image.width(), image.height()) != (self.width as usize, self.height as usize) {
+            return Err(invalid(format!(
+                "frame is {}x{}, expected {}x{}",
+                image.width(),
+                image.height(),
+                self.width,
+                self.height
+            )));
+        }
+
+        let mut palette: Vec<Rgb> = vec![];
+        let mut indices: HashMap<Rgb, u8> = HashMap::new();
+        let mut pixels = Vec::with_capacity(image.pixels().len());
+        for &pixel in image.pixels() {
+            let index = match indices.get(&pixel) {
+                Some(&index) => index,
+                None if palette.len() < 256 => {
+                    let index = palette.len() as u8;
+                    palette.push(pixel);
+                    indices.insert(pixel, index);
+                    index
+                }
+                None => return Err(invalid("frame has more than 256 colours")),
+            };
+            pixels.push(index);
+        }
+
+        // Colour tables have 2^(n + 1) entries; LZW needs at least 2 bits.
+        let table_bits = (usize::BITS - (palette.len().max(2) - 1).leading_zeros()).max(1);
+        let min_code_size = table_bits.max(2);
+
+        self.out.write_all(&[0x21, 0xf9, 4, 0])?;
+        self.out.write_all(&delay.to_le_bytes())?;
+        self.out.write_all(&[0, 0])?;
+
+        self.out.write_all(&[0x2c, 0, 0, 0, 0])?;
+        self.out.write_all(&self.width.to_le_bytes())?;
+        self.out.write_all(&self.height.to_le_bytes())?;
+        self.out.write_all(&[0x80 | (table_bits as u8 - 1)])?;
+        for i in 0..1 << table_bits {
+            let Rgb(r, g, b) = palette.get(i).copied().unwrap_or(Rgb::BLACK);
+            self.out.write_all(&[r, g, b])?;
+        }
+
+        self.out.write_all(&[min_code_size as u8])?;
+        for block in lzw_encode(&pixels, min_code_size).chunks(255) {
+            self.out.write_all(&[block.len() as u8])?;
+            self.out.write_all(block)?;
+        }
+        self.out.write_all(&[0])
+    }
+
+    pub fn finish(mut self) -> io::Result<W> {
+        self.out.write_all(&[0x3b])?;
+        self.out.flush()?;
+
+        Ok(self.out)
+    }
+}
+
+fn invalid(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message.into())
+}
+
+fn dimension(size: usize) -> io::Result<u16> {
+    u16::try_from(size)
+        .ok()
+        .filter(|&size| size > 0)
+        .ok_or_else(|| invalid(format!("{} pixels is not a valid GIF dimension", size)))
+}
+
+/// Packs codes least significant bit first, as GIF wants.
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    bits: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, size: u32) {
+        self.buffer |= (code as u32) << self.bits;
+        self.bits += size;
+        while self.bits >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.bits -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+
+        self.bytes
+    }
+}
+
+/// Variable-width LZW with a clear code whenever the 12-bit table fills up.
+fn lzw_encode(pixels: &[u8], min_code_size: u32) -> Vec<u8> {
+    let clear = 1u16 << min_code_size;
+    let end = clear + 1;
+    let mut out = BitWriter {
+        bytes: vec![],
+        buffer: 0,
+        bits: 0,
+    };
+
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut next = end + 1;
+    let mut size = min_code_size + 1;
+    out.write(clear, size);
+
+    let mut pixels = pixels.iter();
+    let Some(&first) = pixels.next() else {
+        out.write(end, size);
+        return out.finish();
+    };
+    let mut current = first as u16;
+
+    for &pixel in pixels {
+        if let Some(&code) = table.get(&(current, pixel)) {
+            current = code;
+            continue;
+        }
+
+        out.write(current, size);
+        if next < 1 << MAX_CODE_SIZE {
+            table.insert((current, pixel), next);
+            next += 1;
+            // The decoder adds each entry one code later, so it widens one code later too.
+            if next > 1 << size && size < MAX_CODE_SIZE {
+                size += 1;
+            }
+        } else {
+            out.write(clear, size);
+            table.clear();
+            next = end + 1;
+            size = min_code_size + 1;
+        }
+        current = pixel as u16;
+    }
+
+    out.write(current, size);
+    out.write(end, size);
+
+    out.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Grid;
+
+    /// A plain decoder written from the GIF spec, to check the encoder against.
+    fn lzw_decode(data: &[u8], min_code_size: u32) -> Vec<u8> {
+        let clear = 1usize << min_code_size;
+        let mut table: Vec<Vec<u8>> = vec![];
+        let mut size = min_code_size + 1;
+        let (mut bit, mut previous, mut out): (usize, Option<usize>, Vec<u8>) = (0, None, vec![]);
+
+        let reset = |table: &mut Vec<Vec<u8>>| {
+            *table = (0..clear + 2).map(|i| vec![i as u8]).collect();
+        };
+        reset(&mut table);
+
+        loop {
+            let code = (0..size as usize).fold(0, |code, i| {
+                let byte = data[(bit + i) / 8];
+                code | (((byte >> ((bit + i) % 8)) & 1) as usize) << i
+            });
+            bit += size as usize;
+
+            if code == clear {
+                reset(&mut table);
+                size = min_code_size + 1;
+                previous = None;
+                continue;
+            }
+            if code == clear + 1 {
+                return out;
+            }
+
+            let entry = match (code < table.len(), previous) {
+                (true, _) => table[code].clone(),
+                (false, Some(previous)) => {
+                    let mut entry = table[previous].clone();
+                    entry.push(table[previous][0]);
+                    entry
+                }
+                (false, None) => panic!("code {} before any other", code),
+            };
+            if let Some(previous) = previous {
+                if table.len() < 1 << MAX_CODE_SIZE {
+                    let mut added = table[previous].clone();
+                    added.push(entry[0]);
+                    table.push(added);
+                    if table.len() == 1 << size && size < MAX_CODE_SIZE {
+                        size += 1;
+                    }
+                }
+            }
+
+            out.extend(&entry);
+            previous = Some(code);
+        }
+    }
+
+    #[test]
+    fn test_lzw_round_trip() {
+        let mut rng = crate::Rng::new(7);
+        let noisy: Vec<u8> = (0..20_000).map(|_| rng.below(5) as u8).collect();
+        let runs: Vec<u8> = (0..50_000).map(|i| (i / 300 % 3) as u8).collect();
+
+        for (pixels, min_code_size) in [
+            (vec![0], 2),
+            (vec![1, 1, 1, 1, 1, 1, 1], 2),
+            (noisy, 3),
+            (runs, 2),
+            ((0..=255).cycle().take(70_000).collect(), 8),
+        ] {
+            assert_eq!(
+                lzw_decode(&lzw_encode(&pixels, min_code_size), min_code_size),
+                pixels
+            );
+        }
+    }
+
+    #[test]
+    fn test_gif() {
+        let grid = Grid::new(2, 2, vec![0u8, 1, 2, 3]);
+        let image = Image::from_grid(&grid, |&c| Rgb(c * 80, 0, 0));
+
+        let mut gif = GifWriter::new(vec![], 2, 2).unwrap();
+        gif.add_frame(&image, 5).unwrap();
+        gif.add_frame(&image, 5).unwrap();
+        let bytes = gif.finish().unwrap();
+
+        assert_eq!(&bytes[..10], b"GIF89a\x02\x00\x02\x00");
+        assert_eq!(bytes.iter().filter(|&&b| b == 0x2c).count(), 2);
+        assert_eq!(bytes.last(), Some(&0x3b));
+        assert!(GifWriter::new(vec![], 3, 3)
+            .unwrap()
+            .add_frame(&image, 5)
+            .is_err());
+    }
+}
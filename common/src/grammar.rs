@@ -0,0 +1,328 @@
+use std::marker::PhantomData;
+
+use crate::scan::Int;
+use crate::{ParseError, Scanner};
+
+/// A piece of an input format that reads one typed value. Literals are parsers too, reading
+/// themselves and producing `()`, and tuples of parsers read each part in turn.
+pub trait Parser {
+    type Output;
+
+    fn parse_from(&self, scanner: &mut Scanner) -> Result<Self::Output, ParseError>;
+
+    /// Parses all of `input` but surrounding whitespace.
+    fn parse_all(&self, input: &str) -> Result<Self::Output, ParseError> {
+        let mut scanner = Scanner::within(input, input.trim());
+        let output = self.parse_from(&mut scanner)?;
+        scanner.expect_end()?;
+
+        Ok(output)
+    }
+
+    fn map<U, F: Fn(Self::Output) -> U>(self, f: F) -> Map<Self, F>
+    where
+        Self: Sized,
+    {
+        Map { parser: self, f }
+    }
+
+    /// Puts `what` in front of any error message, e.g. "button A: expected \", \"".
+    fn context(self, what: &'static str) -> Context<Self>
+    where
+        Self: Sized,
+    {
+        Context { parser: self, what }
+    }
+}
+
+impl Parser for &str {
+    type Output = ();
+
+    fn parse_from(&self, scanner: &mut Scanner) -> Result<(), ParseError> {
+        scanner.expect_literal(self)
+    }
+}
+
+pub struct Uint<T>(PhantomData<T>);
+
+impl<T: Int> Parser for Uint<T> {
+    type Output = T;
+
+    fn parse_from(&self, scanner: &mut Scanner) -> Result<T, ParseError> {
+        scanner.next_uint()
+    }
+}
+
+/// Digits, after any spaces.
+pub fn uint<T: Int>() -> Uint<T> {
+    Uint(PhantomData)
+}
+
+pub struct SignedInt<T>(PhantomData<T>);
+
+impl<T: Int> Parser for SignedInt<T> {
+    type Output = T;
+
+    fn parse_from(&self, scanner: &mut Scanner) -> Result<T, ParseError> {
+        scanner.next_int()
+    }
+}
+
+/// Digits with an optional sign, after any spaces.
+pub fn int<T: Int>() -> SignedInt<T> {
+    SignedInt(PhantomData)
+}
+
+pub struct Spaces;
+
+impl Parser for Spaces {
+    type Output = ();
+
+    fn parse_from(&self, scanner: &mut Scanner) -> Result<(), ParseError> {
+        let start = scanner.rest().len();
+        scanner.skip_spaces();
+        if scanner.rest().len() == start {
+            return Err(scanner.fail("expected a space"));
+        }
+        if matches!(scanner.peek(), None | Some(b'\n')) {
+            return Err(scanner.fail("expected more after the spaces"));
+        }
+
+        Ok(())
+    }
+}
+
+/// A run of spaces and tabs with more on the line after it, to separate items like
+/// `1  2\t3`. Spaces at the end of a line are left for whatever reads the line's end.
+pub fn spaces() -> Spaces {
+    Spaces
+}
+
+pub struct Map<P, F> {
+    parser: P,
+    f: F,
+}
+
+impl<P: Parser, U, F: Fn(P::Output) -> U> Parser for Map<P, F> {
+    type Output = U;
+
+    fn parse_from(&self, scanner: &mut Scanner) -> Result<U, ParseError> {
+        self.parser.parse_from(scanner).map(&self.f)
+    }
+}
+
+pub struct Context<P> {
+    parser: P,
+    what: &'static str,
+}
+
+impl<P: Parser> Parser for Context<P> {
+    type Output = P::Output;
+
+    fn parse_from(&self, scanner: &mut Scanner) -> Result<P::Output, ParseError> {
+        self.parser.parse_from(scanner).map_err(|err| ParseError {
+            message: format!("{}: {}", self.what, err.message),
+            ..err
+        })
+    }
+}
+
+macro_rules! impl_sequence {
+    ($($p:ident),*) => {
+        #[allow(non_snake_case)]
+        impl<$($p: Parser),*> Parser for ($($p,)*) {
+            type Output = ($($p::Output,)*);
+
+            fn parse_from(&self, scanner: &mut Scanner) -> Result<Self::Output, ParseError> {
+                let ($($p,)*) = self;
+                Ok(($($p.parse_from(scanner)?,)*))
+            }
+        }
+    };
+}
+
+impl_sequence!(A, B);
+impl_sequence!(A, B, C);
+impl_sequence!(A, B, C, D);
+impl_sequence!(A, B, C, D, E);
+impl_sequence!(A, B, C, D, E, F);
+
+/// `prefix` then `parser`, keeping what `parser` read.
+pub fn preceded<'s, P: Parser + 's>(
+    prefix: &'s str,
+    parser: P,
+) -> impl Parser<Output = P::Output> + 's {
+    (prefix, parser).map(|((), output)| output)
+}
+
+/// `parser` then `suffix`, keeping what `parser` read.
+pub fn terminated<'s, P: Parser + 's>(
+    parser: P,
+    suffix: &'s str,
+) -> impl Parser<Output = P::Output> + 's {
+    (parser, suffix).map(|(output, ())| output)
+}
+
+pub struct Separated<P, S> {
+    item: P,
+    separator: S,
+}
+
+impl<P: Parser, S: Parser> Parser for Separated<P, S> {
+    type Output = Vec<P::Output>;
+
+    fn parse_from(&self, scanner: &mut Scanner) -> Result<Self::Output, ParseError> {
+        let mut items = vec![self.item.parse_from(scanner)?];
+
+        loop {
+            let before = scanner.clone();
+            if self.separator.parse_from(scanner).is_err() {
+                *scanner = before;
+                return Ok(items);
+            }
+            items.push(self.item.parse_from(scanner)?);
+        }
+    }
+}
+
+/// One or more `item`s with `separator` between them. The list ends at the first place the
+/// separator does not follow; an item that fails after a separator is an error.
+pub fn separated<P: Parser, S: Parser>(item: P, separator: S) -> Separated<P, S> {
+    Separated { item, separator }
+}
+
+pub struct Section<P>(P);
+
+impl<P: Parser> Parser for Section<P> {
+    type Output = P::Output;
+
+    fn parse_from(&self, scanner: &mut Scanner) -> Result<P::Output, ParseError> {
+        let mut section = scanner
+            .next_section()
+            .ok_or_else(|| scanner.fail("expected another section"))?;
+        let output = self.0.parse_from(&mut section)?;
+        section.expect_end()?;
+
+        Ok(output)
+    }
+}
+
+/// `parser` over everything up to the next blank line, which it must read all of.
+pub fn section<P: Parser>(parser: P) -> Section<P> {
+    Section(parser)
+}
+
+pub struct Sections<P>(P);
+
+impl<P: Parser> Parser for Sections<P> {
+    type Output = Vec<P::Output>;
+
+    fn parse_from(&self, scanner: &mut Scanner) -> Result<Self::Output, ParseError> {
+        let mut outputs = vec![];
+        while let Some(mut section) = scanner.next_section() {
+            outputs.push(self.0.parse_from(&mut section)?);
+            section.expect_end()?;
+        }
+
+        Ok(outputs)
+    }
+}
+
+/// `parser` over every remaining blank-line separated section.
+pub fn sections<P: Parser>(parser: P) -> Sections<P> {
+    Sections(parser)
+}
+
+pub struct Lines<P>(P);
+
+impl<P: Parser> Parser for Lines<P> {
+    type Output = Vec<P::Output>;
+
+    fn parse_from(&self, scanner: &mut Scanner) -> Result<Self::Output, ParseError> {
+        let mut outputs = vec![];
+        while let Some(mut line) = scanner.next_line() {
+            outputs.push(self.0.parse_from(&mut line)?);
+            line.expect_end()?;
+        }
+
+        Ok(outputs)
+    }
+}
+
+/// `parser` over every remaining line, which it must read all of but trailing spaces.
+pub fn lines<P: Parser>(parser: P) -> Lines<P> {
+    Lines(parser)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequence() {
+        let robot = (
+            preceded("p=", uint::<u8>()),
+            preceded(",", uint::<u8>()),
+            preceded(" v=", int::<i8>()),
+            preceded(",", int::<i8>()),
+        );
+
+        assert_eq!(robot.parse_all("p=0,4 v=3,-3\n"), Ok((0, 4, 3, -3)));
+
+        let err = robot.parse_all("p=0,4 v=3;-3").unwrap_err();
+        assert_eq!((err.column, err.message.as_str()), (10, "expected \",\""));
+        assert_eq!(err.text, ";-3");
+
+        let err = robot.parse_all("p=0,4 v=3,-3 extra").unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (14, "extra"));
+    }
+
+    #[test]
+    fn test_separated() {
+        let list = separated(uint::<u32>(), ",");
+
+        assert_eq!(list.parse_all("75,47,61"), Ok(vec![75, 47, 61]));
+        assert_eq!(list.parse_all("75,x").unwrap_err().text, "x");
+        assert_eq!(
+            list.parse_all("75;47").unwrap_err().message,
+            "unexpected text"
+        );
+    }
+
+    #[test]
+    fn test_lines() {
+        let rows = lines(separated(uint::<u8>(), spaces()));
+
+        assert_eq!(
+            rows.parse_all("1 2  3 \n4\t5\n"),
+            Ok(vec![vec![1, 2, 3], vec![4, 5]])
+        );
+        assert_eq!(rows.parse_all("1,2").unwrap_err().text, ",2");
+        assert_eq!(rows.parse_all("1 2\n3 x").unwrap_err().line, 2);
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = "1|2\n3|4\n\n5,6\n7\n";
+        let rules = separated((uint::<u8>(), preceded("|", uint::<u8>())), "\n");
+        let updates = separated(separated(uint::<u8>(), ","), "\n");
+
+        assert_eq!(
+            (section(rules), section(updates)).parse_all(input),
+            Ok((vec![(1, 2), (3, 4)], vec![vec![5, 6], vec![7]]))
+        );
+        assert_eq!(
+            sections(separated(uint::<u8>(), "\n").map(|v| v.len())).parse_all("1\n2\n\n3"),
+            Ok(vec![2, 1])
+        );
+
+        let missing = (
+            section(uint::<u8>()),
+            section(uint::<u8>().context("second")),
+        );
+        let err = missing.parse_all("1\n\n\n2 x").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+        assert_eq!(err.message, "second: expected a number");
+        assert!(missing.parse_all("1").is_err());
+    }
+}
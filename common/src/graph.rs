@@ -0,0 +1,635 @@
+use std::cmp::Ordering;
+use std::collections::{hash_map, BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Searches take the graph as a function from a node to its neighbours, so an adjacency map
+/// (`|n| map.get(&n).into_iter().flatten().copied()`) and a grid (`|p| grid.neighbors4(p)`)
+/// work the same way. Weighted searches want `(neighbour, cost)` pairs instead.
+pub trait Node: Copy + Eq + Hash {}
+
+impl<N: Copy + Eq + Hash> Node for N {}
+
+/// Edge and path costs: anything ordered that adds up, with `default()` as zero.
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<C: Copy + Ord + Default + Add<Output = C>> Cost for C {}
+
+/// How far every reached node is from the nearest start, and the node each was reached from.
+#[derive(Debug, Clone)]
+pub struct Paths<N, C> {
+    dist: HashMap<N, C>,
+    parent: HashMap<N, N>,
+}
+
+impl<N: Node, C: Cost> Paths<N, C> {
+    pub fn distance(&self, node: N) -> Option<C> {
+        self.dist.get(&node).copied()
+    }
+
+    pub fn contains(&self, node: N) -> bool {
+        self.dist.contains_key(&node)
+    }
+
+    /// The node `node` was reached from; `None` for starts and unreached nodes.
+    pub fn parent(&self, node: N) -> Option<N> {
+        self.parent.get(&node).copied()
+    }
+
+    /// A shortest path from a start to `node`, both included.
+    pub fn path_to(&self, node: N) -> Option<Vec<N>> {
+        if !self.contains(node) {
+            return None;
+        }
+
+        let mut path = vec![node];
+        while let Some(parent) = self.parent(*path.last().unwrap()) {
+            path.push(parent);
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Every reached node, starts included, in no particular order.
+    pub fn nodes(&self) -> impl Iterator<Item = N> + '_ {
+        self.dist.keys().copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.dist.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dist.is_empty()
+    }
+}
+
+/// Breadth-first search from every start at once, counting steps.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(N) -> I,
+) -> Paths<N, usize>
+where
+    N: Node,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths {
+        dist: HashMap::new(),
+        parent: HashMap::new(),
+    };
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if paths.dist.insert(start, 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        let next = paths.dist[&node] + 1;
+        for neighbor in neighbors(node) {
+            if let hash_map::Entry::Vacant(entry) = paths.dist.entry(neighbor) {
+                entry.insert(next);
+                paths.parent.insert(neighbor, node);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    paths
+}
+
+/// A heap entry ordered so the lowest `priority` comes out of a `BinaryHeap` first.
+struct Entry<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Entry<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Entry<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Entry<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Entry<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// Cheapest paths from every start at once. Costs must not be negative.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(N) -> I,
+) -> Paths<N, C>
+where
+    N: Node,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths {
+        dist: HashMap::new(),
+        parent: HashMap::new(),
+    };
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        paths.dist.insert(start, C::default());
+        heap.push(Entry {
+            priority: C::default(),
+            cost: C::default(),
+            node: start,
+        });
+    }
+
+    while let Some(Entry { cost, node, .. }) = heap.pop() {
+        if paths.dist[&node] < cost {
+            continue;
+        }
+
+        for (neighbor, step) in neighbors(node) {
+            let next = cost + step;
+            if paths.dist.get(&neighbor).is_none_or(|&known| next < known) {
+                paths.dist.insert(neighbor, next);
+                paths.parent.insert(neighbor, node);
+                heap.push(Entry {
+                    priority: next,
+                    cost: next,
+                    node: neighbor,
+                });
+            }
+        }
+    }
+
+    paths
+}
+
+/// The cost of a cheapest path from `start` to a node `is_goal` accepts, and the path. The
+/// `heuristic` guesses the cost left from a node; it must never guess too high, and a constant
+/// zero makes this Dijkstra's search.
+pub fn astar<N, C, I>(
+    start: N,
+    mut is_goal: impl FnMut(N) -> bool,
+    mut neighbors: impl FnMut(N) -> I,
+    mut heuristic: impl FnMut(N) -> C,
+) -> Option<(C, Vec<N>)>
+where
+    N: Node,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths {
+        dist: HashMap::from([(start, C::default())]),
+        parent: HashMap::new(),
+    };
+    let mut heap = BinaryHeap::from([Entry {
+        priority: heuristic(start),
+        cost: C::default(),
+        node: start,
+    }]);
+
+    while let Some(Entry { cost, node, .. }) = heap.pop() {
+        if paths.dist[&node] < cost {
+            continue;
+        }
+        if is_goal(node) {
+            return Some((cost, paths.path_to(node).unwrap()));
+        }
+
+        for (neighbor, step) in neighbors(node) {
+            let next = cost + step;
+            if paths.dist.get(&neighbor).is_none_or(|&known| next < known) {
+                paths.dist.insert(neighbor, next);
+                paths.parent.insert(neighbor, node);
+                heap.push(Entry {
+                    priority: next + heuristic(neighbor),
+                    cost: next,
+                    node: neighbor,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+/// Like `Paths`, but keeping every node each node can be reached from along a cheapest path.
+#[derive(Debug, Clone)]
+pub struct AllPaths<N, C> {
+    dist: HashMap<N, C>,
+    parents: HashMap<N, Vec<N>>,
+}
+
+impl<N: Node, C: Cost> AllPaths<N, C> {
+    pub fn distance(&self, node: N) -> Option<C> {
+        self.dist.get(&node).copied()
+    }
+
+    pub fn contains(&self, node: N) -> bool {
+        self.dist.contains_key(&node)
+    }
+
+    /// Every node `node` is reached from along a cheapest path.
+    pub fn parents(&self, node: N) -> &[N] {
+        self.parents.get(&node).map_or(&[], Vec::as_slice)
+    }
+
+    /// Every cheapest path from a start to `node`, both included. There can be a great many.
+    pub fn paths_to(&self, node: N) -> Vec<Vec<N>> {
+        if !self.contains(node) {
+            return vec![];
+        }
+        if self.parents(node).is_empty() {
+            return vec![vec![node]];
+        }
+
+        self.parents(node)
+            .iter()
+            .flat_map(|&parent| self.paths_to(parent))
+            .map(|mut path| {
+                path.push(node);
+                path
+            })
+            .collect()
+    }
+
+    /// How many cheapest paths lead to each reached node, without listing them.
+    pub fn path_counts(&self) -> HashMap<N, u64> {
+        let mut nodes: Vec<N> = self.nodes().collect();
+        nodes.sort_by_key(|node| self.dist[node]);
+
+        let mut counts = HashMap::new();
+        for node in nodes {
+            let count = match self.parents(node) {
+                [] => 1,
+                parents => parents.iter().map(|parent| counts[parent]).sum(),
+            };
+            counts.insert(node, count);
+        }
+
+        counts
+    }
+
+    /// Every reached node, starts included, in no particular order.
+    pub fn nodes(&self) -> impl Iterator<Item = N> + '_ {
+        self.dist.keys().copied()
+    }
+}
+
+/// Cheapest paths from every start at once, keeping all the ties. Costs must be positive.
+pub fn all_shortest_paths<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(N) -> I,
+) -> AllPaths<N, C>
+where
+    N: Node,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = AllPaths {
+        dist: HashMap::new(),
+        parents: HashMap::new(),
+    };
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        paths.dist.insert(start, C::default());
+        heap.push(Entry {
+            priority: C::default(),
+            cost: C::default(),
+            node: start,
+        });
+    }
+
+    while let Some(Entry { cost, node, .. }) = heap.pop() {
+        if paths.dist[&node] < cost {
+            continue;
+        }
+
+        for (neighbor, step) in neighbors(node) {
+            let next = cost + step;
+            match paths.dist.get(&neighbor).map(|known| next.cmp(known)) {
+                Some(Ordering::Greater) => {}
+                Some(Ordering::Equal) => paths.parents.entry(neighbor).or_default().push(node),
+                Some(Ordering::Less) | None => {
+                    paths.dist.insert(neighbor, next);
+                    paths.parents.insert(neighbor, vec![node]);
+                    heap.push(Entry {
+                        priority: next,
+                        cost: next,
+                        node: neighbor,
+                    });
+                }
+            }
+        }
+    }
+
+    paths
+}
+
+/// `nodes` and everything reachable from them, in first-seen order, with their neighbours.
+fn explore<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(N) -> I,
+) -> (Vec<N>, HashMap<N, Vec<N>>)
+where
+    N: Node,
+    I: IntoIterator<Item = N>,
+{
+    let mut order: Vec<N> = vec![];
+    let mut edges = HashMap::new();
+    let mut seen = HashSet::new();
+    for node in nodes {
+        if seen.insert(node) {
+            order.push(node);
+        }
+    }
+
+    let mut i = 0;
+    while i < order.len() {
+        let next: Vec<N> = neighbors(order[i]).into_iter().collect();
+        for &neighbor in &next {
+            if seen.insert(neighbor) {
+                order.push(neighbor);
+            }
+        }
+        edges.insert(order[i], next);
+        i += 1;
+    }
+
+    (order, edges)
+}
+
+/// `nodes` and everything reachable from them, each before all its neighbours. Ties keep the
+/// order nodes are given or first found in. A cycle makes this impossible; the error is one
+/// such cycle, each node followed by a neighbour and the last by the first.
+pub fn toposort<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    neighbors: impl FnMut(N) -> I,
+) -> Result<Vec<N>, Vec<N>>
+where
+    N: Node,
+    I: IntoIterator<Item = N>,
+{
+    let (order, edges) = explore(nodes, neighbors);
+
+    let mut incoming: HashMap<N, Vec<N>> = HashMap::new();
+    for &node in &order {
+        for &neighbor in &edges[&node] {
+            incoming.entry(neighbor).or_default().push(node);
+        }
+    }
+    let mut waiting: HashMap<N, usize> = order
+        .iter()
+        .map(|node| (*node, incoming.get(node).map_or(0, Vec::len)))
+        .collect();
+
+    let mut queue: VecDeque<N> = order.iter().copied().filter(|n| waiting[n] == 0).collect();
+    let mut sorted = vec![];
+    while let Some(node) = queue.pop_front() {
+        sorted.push(node);
+        for neighbor in &edges[&node] {
+            let left = waiting.get_mut(neighbor).unwrap();
+            *left -= 1;
+            if *left == 0 {
+                queue.push_back(*neighbor);
+            }
+        }
+    }
+
+    if sorted.len() == order.len() {
+        return Ok(sorted);
+    }
+
+    // Every node left waits on another one left, so walking back along those must loop.
+    let mut node = *order.iter().find(|node| waiting[*node] > 0).unwrap();
+    let (mut walked, mut at) = (vec![], HashMap::new());
+    while !at.contains_key(&node) {
+        at.insert(node, walked.len());
+        walked.push(node);
+        node = *incoming[&node].iter().find(|n| waiting[*n] > 0).unwrap();
+    }
+    let mut cycle = walked.split_off(at[&node]);
+    cycle.reverse();
+
+    Err(cycle)
+}
+
+struct Tarjan<N> {
+    index: HashMap<N, usize>,
+    low: HashMap<N, usize>,
+    stack: Vec<N>,
+    on_stack: HashSet<N>,
+    components: Vec<Vec<N>>,
+}
+
+impl<N: Node> Tarjan<N> {
+    fn enter(&mut self, node: N) {
+        let index = self.index.len();
+        self.index.insert(node, index);
+        self.low.insert(node, index);
+        self.stack.push(node);
+        self.on_stack.insert(node);
+    }
+
+    fn lower(&mut self, node: N, to: usize) {
+        let low = self.low.get_mut(&node).unwrap();
+        *low = (*low).min(to);
+    }
+
+    fn leave(&mut self, node: N) {
+        if self.low[&node] != self.index[&node] {
+            return;
+        }
+
+        let mut component = vec![];
+        loop {
+            let member = self.stack.pop().unwrap();
+            self.on_stack.remove(&member);
+            component.push(member);
+            if member == node {
+                break;
+            }
+        }
+        self.components.push(component);
+    }
+}
+
+/// The strongly connected components of `nodes` and everything reachable from them: the groups
+/// in which every node reaches every other. Each component comes after every component it has
+/// an edge into, so the list is a topological order of the components reversed.
+pub fn scc<N, I>(nodes: impl IntoIterator<Item = N>, neighbors: impl FnMut(N) -> I) -> Vec<Vec<N>>
+where
+    N: Node,
+    I: IntoIterator<Item = N>,
+{
+    let (order, edges) = explore(nodes, neighbors);
+    let mut tarjan = Tarjan {
+        index: HashMap::new(),
+        low: HashMap::new(),
+        stack: vec![],
+        on_stack: HashSet::new(),
+        components: vec![],
+    };
+
+    for root in order {
+        if tarjan.index.contains_key(&root) {
+            continue;
+        }
+
+        // The recursion of the textbook version, kept on the heap: each node and how many of its
+        // neighbours have been looked at.
+        tarjan.enter(root);
+        let mut calls = vec![(root, 0)];
+        while let Some((node, next)) = calls.last_mut() {
+            let node = *node;
+            match edges[&node].get(*next) {
+                Some(&neighbor) => {
+                    *next += 1;
+                    if !tarjan.index.contains_key(&neighbor) {
+                        tarjan.enter(neighbor);
+                        calls.push((neighbor, 0));
+                    } else if tarjan.on_stack.contains(&neighbor) {
+                        tarjan.lower(node, tarjan.index[&neighbor]);
+                    }
+                }
+                None => {
+                    calls.pop();
+                    if let Some(&(caller, _)) = calls.last() {
+                        tarjan.lower(caller, tarjan.low[&node]);
+                    }
+                    tarjan.leave(node);
+                }
+            }
+        }
+    }
+
+    tarjan.components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Dir4, Grid, Point};
+
+    fn maze() -> Grid<char> {
+        Grid::parse("S.#.\n..#.\n#...\n..#E\n", Some).unwrap()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = maze();
+        let open = |pos: Point| grid.neighbors4(pos).filter(|&next| grid[next] != '#');
+        let paths = bfs([Point::from_row_col(0, 0)], open);
+
+        let end = grid.find(|&c| c == 'E').unwrap();
+        assert_eq!(paths.distance(end), Some(6));
+        let path = paths.path_to(end).unwrap();
+        assert_eq!(
+            (path.len(), path[0], path[6]),
+            (7, Point::from_row_col(0, 0), end)
+        );
+        assert_eq!(paths.len(), 12);
+        assert!(!paths.contains(Point::from_row_col(0, 2)));
+    }
+
+    #[test]
+    fn test_weighted() {
+        let roads: HashMap<char, Vec<(char, u32)>> = HashMap::from([
+            ('a', vec![('b', 7), ('c', 9), ('f', 14)]),
+            ('b', vec![('c', 10), ('d', 15)]),
+            ('c', vec![('d', 11), ('f', 2)]),
+            ('d', vec![('e', 6)]),
+            ('f', vec![('e', 9)]),
+        ]);
+        let roads_from = |town: char| roads.get(&town).into_iter().flatten().copied();
+
+        let paths = dijkstra(['a'], roads_from);
+        assert_eq!(paths.distance('e'), Some(20));
+        assert_eq!(paths.path_to('e'), Some(vec!['a', 'c', 'f', 'e']));
+        assert_eq!(paths.distance('g'), None);
+
+        assert_eq!(
+            astar('a', |town| town == 'e', roads_from, |_| 0),
+            Some((20, vec!['a', 'c', 'f', 'e']))
+        );
+        assert_eq!(astar('e', |town| town == 'a', roads_from, |_| 0), None);
+
+        let grid = maze();
+        let end = Point::from_row_col(3, 3);
+        let open = |pos: Point| {
+            grid.neighbors4(pos)
+                .filter(|&next| grid[next] != '#')
+                .map(|next| (next, 1))
+        };
+        let manhattan = |pos: Point| pos.row.abs_diff(3) + pos.col.abs_diff(3);
+        let (cost, path) =
+            astar(Point::from_row_col(0, 0), |pos| pos == end, open, manhattan).unwrap();
+        assert_eq!((cost, path.len()), (6, 7));
+    }
+
+    #[test]
+    fn test_all_shortest_paths() {
+        let grid = &Grid::filled(3, 3, ());
+        let steps = |pos: Point| {
+            [Dir4::Right, Dir4::Down]
+                .into_iter()
+                .filter_map(move |delta| grid.offset(pos, delta))
+                .map(|next| (next, 1))
+        };
+        let paths = all_shortest_paths([Point::from_row_col(0, 0)], steps);
+        let corner = Point::from_row_col(2, 2);
+
+        assert_eq!(paths.distance(corner), Some(4));
+        assert_eq!(paths.paths_to(corner).len(), 6);
+        assert_eq!(paths.path_counts()[&corner], 6);
+        assert_eq!(paths.path_counts()[&Point::from_row_col(1, 1)], 2);
+        assert_eq!(paths.parents(Point::from_row_col(0, 0)), []);
+    }
+
+    #[test]
+    fn test_toposort() {
+        let after: HashMap<u32, Vec<u32>> = HashMap::from([
+            (5, vec![11]),
+            (7, vec![11, 8]),
+            (3, vec![8, 10]),
+            (11, vec![2]),
+        ]);
+        let next = |n: u32| after.get(&n).into_iter().flatten().copied();
+
+        assert_eq!(toposort([5, 7, 3], next), Ok(vec![5, 7, 3, 11, 8, 10, 2]));
+
+        let mut looped = after.clone();
+        looped.insert(2, vec![7]);
+        let cycle =
+            toposort([5, 7, 3], |n| looped.get(&n).into_iter().flatten().copied()).unwrap_err();
+        assert_eq!(cycle.len(), 3);
+        for (i, node) in cycle.iter().enumerate() {
+            assert!(looped[node].contains(&cycle[(i + 1) % cycle.len()]));
+        }
+    }
+
+    #[test]
+    fn test_scc() {
+        let edges: HashMap<u8, Vec<u8>> = HashMap::from([
+            (1, vec![2]),
+            (2, vec![3]),
+            (3, vec![1, 4]),
+            (4, vec![5]),
+            (5, vec![4, 6]),
+        ]);
+        let mut components = scc([1], |n| edges.get(&n).into_iter().flatten().copied());
+        for component in &mut components {
+            component.sort();
+        }
+
+        assert_eq!(components, [vec![6], vec![4, 5], vec![1, 2, 3]]);
+    }
+}
@@ -0,0 +1,299 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::point::{Dir4, Dir8, Point, Vec2};
+use crate::ParseError;
+
+/// A rectangular grid stored row-major in a single `Vec`, addressed by `Point`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(rows: usize, cols: usize, cells: Vec<T>) -> Self {
+        assert_eq!(rows * cols, cells.len(), "grid size does not match cells");
+
+        Self { cells, rows, cols }
+    }
+
+    pub fn filled(rows: usize, cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(rows, cols, vec![value; rows * cols])
+    }
+
+    /// Parses the trimmed `input`, one row per line, mapping each char through `cell`.
+    pub fn parse(input: &str, cell: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        Self::parse_section(input, input.trim(), cell)
+    }
+
+    /// Like `parse`, for a `section` sliced out of `input` so errors point into the whole input.
+    pub fn parse_section(
+        input: &str,
+        section: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = vec![];
+        let mut rows = 0;
+        let mut cols = None;
+
+        for line in section.split("\n") {
+            let before = cells.len();
+            for (j, c) in line.char_indices() {
+                cells.push(cell(c).ok_or_else(|| {
+                    ParseError::at(input, &line[j..j + c.len_utf8()], "unknown cell")
+                })?);
+            }
+
+            let width = cells.len() - before;
+            if *cols.get_or_insert(width) != width {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("expected {} columns", cols.unwrap()),
+                ));
+            }
+            rows += 1;
+        }
+
+        match cols {
+            Some(cols) if cols > 0 => Ok(Self::new(rows, cols, cells)),
+            _ => Err(ParseError::at(input, section, "empty grid")),
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    pub fn in_bounds(&self, pos: Point) -> bool {
+        pos.row < self.rows && pos.col < self.cols
+    }
+
+    pub fn get(&self, pos: Point) -> Option<&T> {
+        if self.in_bounds(pos) {
+            Some(&self.cells[pos.row * self.cols + pos.col])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Point) -> Option<&mut T> {
+        if self.in_bounds(pos) {
+            Some(&mut self.cells[pos.row * self.cols + pos.col])
+        } else {
+            None
+        }
+    }
+
+    /// The position `delta` away from `pos`, if it is still inside the grid.
+    pub fn offset(&self, pos: Point, delta: impl Into<Vec2>) -> Option<Point> {
+        pos.checked_add(delta).filter(|&next| self.in_bounds(next))
+    }
+
+    /// In-bounds orthogonal neighbours, in `Dir4::ALL` order.
+    pub fn neighbors4(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        Dir4::ALL
+            .into_iter()
+            .filter_map(move |dir| self.offset(pos, dir))
+    }
+
+    /// In-bounds orthogonal and diagonal neighbours, in `Dir8::ALL` order.
+    pub fn neighbors8(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        Dir8::ALL
+            .into_iter()
+            .filter_map(move |dir| self.offset(pos, dir))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.cols)
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(col).step_by(self.cols)
+    }
+
+    pub fn iter_columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.cols).map(|col| self.column(col))
+    }
+
+    /// Every position in row-major order.
+    pub fn coords(&self) -> impl Iterator<Item = Point> {
+        let cols = self.cols;
+        (0..self.rows * cols).map(move |i| Point::from_row_col(i / cols, i % cols))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.coords().zip(self.cells.iter())
+    }
+
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn positions<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Point> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.rows, self.cols, self.cells.iter().map(f).collect())
+    }
+
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.cols, self.rows, |pos| {
+            Point::from_row_col(pos.col, pos.row)
+        })
+    }
+
+    /// Rotates a quarter turn clockwise.
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.cols, self.rows, |pos| {
+            Point::from_row_col(self.rows - 1 - pos.col, pos.row)
+        })
+    }
+
+    /// Rotates a quarter turn counter-clockwise.
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.cols, self.rows, |pos| {
+            Point::from_row_col(pos.col, self.cols - 1 - pos.row)
+        })
+    }
+
+    fn rebuild(&self, rows: usize, cols: usize, source: impl Fn(Point) -> Point) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..rows * cols)
+            .map(|i| self[source(Point::from_row_col(i / cols, i % cols))].clone())
+            .collect();
+
+        Self::new(rows, cols, cells)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside a {:?} grid", pos, self.size()))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, pos: Point) -> &mut T {
+        let size = self.size();
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside a {:?} grid", pos, size))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.iter_rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::parse("\nabc\ndef\n", Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = sample();
+
+        assert_eq!(grid.size(), (2, 3));
+        assert_eq!(grid[Point::from_row_col(1, 2)], 'f');
+        assert_eq!(grid.get(Point::from_row_col(2, 0)), None);
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Grid::parse("ab\nc\n", Some).unwrap_err();
+        assert_eq!((err.line, err.message.as_str()), (2, "expected 2 columns"));
+
+        let err = Grid::parse("..\n.#\n", |c| (c == '.').then_some(())).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = sample();
+
+        assert_eq!(
+            grid.neighbors4(Point::from_row_col(0, 0))
+                .collect::<Vec<_>>(),
+            vec![Point::from_row_col(0, 1), Point::from_row_col(1, 0)]
+        );
+        assert_eq!(grid.neighbors8(Point::from_row_col(1, 1)).count(), 5);
+        assert_eq!(grid.offset(Point::from_row_col(1, 2), Dir4::Right), None);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = sample();
+
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.iter_columns().count(), 3);
+        assert_eq!(grid.find(|&c| c == 'e'), Some(Point::from_row_col(1, 1)));
+        assert_eq!(
+            grid.positions(|&c| c < 'c').collect::<Vec<_>>(),
+            vec![Point::from_row_col(0, 0), Point::from_row_col(0, 1)]
+        );
+    }
+
+    #[test]
+    fn test_transforms() {
+        let grid = sample();
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_left().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+    }
+}
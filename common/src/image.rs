@@ -0,0 +1,275 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::Grid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+
+    /// A fully saturated colour, `hue` going once round the wheel over 0.0..1.0.
+    pub fn hue(hue: f64) -> Self {
+        let h = hue.rem_euclid(1.0) * 6.0;
+        let x = (255.0 * (1.0 - (h % 2.0 - 1.0).abs())).round() as u8;
+
+        match h as u8 {
+            0 => Rgb(255, x, 0),
+            1 => Rgb(x, 255, 0),
+            2 => Rgb(0, 255, x),
+            3 => Rgb(0, x, 255),
+            4 => Rgb(x, 0, 255),
+            _ => Rgb(255, 0, x),
+        }
+    }
+
+    pub fn luma(self) -> u8 {
+        ((299 * self.0 as u32 + 587 * self.1 as u32 + 114 * self.2 as u32) / 1000) as u8
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Pbm,
+    Pgm,
+    Ppm,
+    Png,
+}
+
+impl ImageFormat {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "pbm" => Some(ImageFormat::Pbm),
+            "pgm" => Some(ImageFormat::Pgm),
+            "ppm" => Some(ImageFormat::Ppm),
+            "png" => Some(ImageFormat::Png),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// One pixel per cell, coloured by `palette`.
+    pub fn from_grid<T>(grid: &Grid<T>, palette: impl Fn(&T) -> Rgb) -> Self {
+        Self {
+            width: grid.cols(),
+            height: grid.rows(),
+            pixels: grid.iter().map(|(_, cell)| palette(cell)).collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Row by row, top to bottom.
+    pub fn pixels(&self) -> &[Rgb] {
+        &self.pixels
+    }
+
+    /// Every pixel blown up to a `factor`×`factor` square.
+    pub fn scaled(&self, factor: usize) -> Self {
+        let factor = factor.max(1);
+        let pixels = (0..self.height * factor)
+            .flat_map(|y| {
+                (0..self.width * factor)
+                    .map(move |x| self.pixels[y / factor * self.width + x / factor])
+            })
+            .collect();
+
+        Self {
+            width: self.width * factor,
+            height: self.height * factor,
+            pixels,
+        }
+    }
+
+    fn rows(&self) -> impl Iterator<Item = &[Rgb]> {
+        self.pixels.chunks(self.width.max(1))
+    }
+
+    /// Binary PBM: dark pixels (luma below half) are set.
+    pub fn to_pbm(&self) -> Vec<u8> {
+        let mut out = format!("P4\n{} {}\n", self.width, self.height).into_bytes();
+        for row in self.rows() {
+            for byte in row.chunks(8) {
+                out.push(byte.iter().enumerate().fold(0, |acc, (i, pixel)| {
+                    acc | ((pixel.luma() < 128) as u8) << (7 - i)
+                }));
+            }
+        }
+
+        out
+    }
+
+    /// Binary PGM, 8 bits of luma per pixel.
+    pub fn to_pgm(&self) -> Vec<u8> {
+        let mut out = format!("P5\n{} {}\n255\n", self.width, self.height).into_bytes();
+        out.extend(self.pixels.iter().map(|pixel| pixel.luma()));
+
+        out
+    }
+
+    /// Binary PPM, 8 bits per channel.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut out = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        out.extend(
+            self.pixels
+                .iter()
+                .flat_map(|pixel| [pixel.0, pixel.1, pixel.2]),
+        );
+
+        out
+    }
+
+    /// 8-bit RGB PNG. The image data is stored, not compressed, so it is about as big as the PPM.
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut raw = Vec::with_capacity(self.height * (1 + 3 * self.width));
+        for row in self.rows() {
+            raw.push(0);
+            raw.extend(row.iter().flat_map(|pixel| [pixel.0, pixel.1, pixel.2]));
+        }
+
+        let mut header = vec![];
+        header.extend((self.width as u32).to_be_bytes());
+        header.extend((self.height as u32).to_be_bytes());
+        header.extend([8, 2, 0, 0, 0]);
+
+        let mut out = b"\x89PNG\r\n\x1a\n".to_vec();
+        png_chunk(&mut out, b"IHDR", &header);
+        png_chunk(&mut out, b"IDAT", &zlib_stored(&raw));
+        png_chunk(&mut out, b"IEND", &[]);
+
+        out
+    }
+
+    pub fn encode(&self, format: ImageFormat) -> Vec<u8> {
+        match format {
+            ImageFormat::Pbm => self.to_pbm(),
+            ImageFormat::Pgm => self.to_pgm(),
+            ImageFormat::Ppm => self.to_ppm(),
+            ImageFormat::Png => self.to_png(),
+        }
+    }
+
+    /// Writes the image in the format named by `path`'s extension.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let format = ImageFormat::from_path(path).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "expected a .pbm, .pgm, .ppm or .png file",
+            )
+        })?;
+
+        fs::write(path, self.encode(format))
+    }
+}
+
+fn png_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend((data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend(kind);
+    out.extend(data);
+    let crc = crc32(&out[start..]);
+    out.extend(crc.to_be_bytes());
+}
+
+/// A zlib stream of uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+
+    if blocks.peek().is_none() {
+        out.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        out.push(blocks.peek().is_none() as u8);
+        out.extend((block.len() as u16).to_le_bytes());
+        out.extend((!(block.len() as u16)).to_le_bytes());
+        out.extend(block);
+    }
+    out.extend(adler32(data).to_be_bytes());
+
+    out
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(!0u32, |crc, &byte| {
+        (0..8).fold(crc ^ byte as u32, |crc, _| {
+            (crc >> 1) ^ (0xedb8_8320 & (crc & 1).wrapping_neg())
+        })
+    })
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (a, b) = data.iter().fold((1u32, 0u32), |(a, b), &byte| {
+        let a = (a + byte as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkerboard() -> Image {
+        let grid = Grid::new(2, 3, vec![true, false, true, false, true, false]);
+        Image::from_grid(&grid, |&on| if on { Rgb::BLACK } else { Rgb::WHITE })
+    }
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn test_netpbm() {
+        let image = checkerboard();
+
+        assert_eq!(image.to_pbm(), b"P4\n3 2\n\xa0\x40");
+        assert_eq!(image.to_pgm(), b"P5\n3 2\n255\n\x00\xff\x00\xff\x00\xff");
+        assert_eq!(&image.to_ppm()[..14], b"P6\n3 2\n255\n\x00\x00\x00");
+    }
+
+    #[test]
+    fn test_png() {
+        let png = checkerboard().scaled(2).to_png();
+
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..24], [0, 0, 0, 6, 0, 0, 0, 4]);
+        assert_eq!(
+            &png[png.len() - 12..],
+            b"\x00\x00\x00\x00IEND\xae\x42\x60\x82"
+        );
+
+        // Four rows of a filter byte and six pixels, in one final stored block.
+        let idat = &png[33 + 8..];
+        assert_eq!(&idat[..3], [0x78, 0x01, 0x01]);
+        assert_eq!(&idat[3..5], 76u16.to_le_bytes());
+    }
+
+    #[test]
+    fn test_hue() {
+        assert_eq!(Rgb::hue(0.0), Rgb(255, 0, 0));
+        assert_eq!(Rgb::hue(1.0 / 3.0), Rgb(0, 255, 0));
+        assert_eq!(Rgb::hue(2.0 / 3.0), Rgb(0, 0, 255));
+    }
+}
@@ -0,0 +1,85 @@
+use std::env;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where a day's inputs live: `$AOC_INPUT_DIR/YEAR/dayN`, falling back to its `crate_dir`.
+pub fn day_dir(year: u16, day: u8, root: &Path) -> PathBuf {
+    let dir = env::var_os(INPUT_DIR_VAR).map_or_else(|| root.to_owned(), PathBuf::from);
+
+    crate_dir(year, day, &dir)
+}
+
+/// The day's crate, `root/YEAR/dayN`, which keeps its answers and submissions whatever
+/// `$AOC_INPUT_DIR` says.
+pub fn crate_dir(year: u16, day: u8, root: &Path) -> PathBuf {
+    root.join(year.to_string()).join(format!("day{}", day))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// `-` means stdin, anything else is a path.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+
+    /// `input.txt` inside the day's `day_dir`.
+    pub fn default_for(year: u16, day: u8, root: &Path) -> Self {
+        InputSource::File(day_dir(year, day, root).join("input.txt"))
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            InputSource::Stdin => {
+                let mut buf = String::new();
+                io::stdin().lock().read_to_string(&mut buf)?;
+                Ok(buf)
+            }
+            InputSource::File(path) => {
+                let mut file = File::open(path).map_err(|err| {
+                    io::Error::new(err.kind(), format!("{}: {}", path.display(), err))
+                })?;
+                let len = file.metadata().map_or(0, |meta| meta.len() as usize);
+
+                let mut buf = String::with_capacity(len);
+                file.read_to_string(&mut buf)?;
+                Ok(buf)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg("2024/day1/input.txt"),
+            InputSource::File(PathBuf::from("2024/day1/input.txt"))
+        );
+    }
+
+    #[test]
+    fn test_read_file() {
+        let path = env::temp_dir().join(format!("aoc-input-{}.txt", std::process::id()));
+        std::fs::write(&path, "3   4\n").unwrap();
+
+        assert_eq!(InputSource::File(path.clone()).read().unwrap(), "3   4\n");
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
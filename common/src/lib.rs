@@ -0,0 +1,43 @@
+pub mod animate;
+pub mod answers;
+mod bench;
+pub mod counter;
+pub mod differential;
+pub mod examples;
+pub mod gif;
+pub mod grammar;
+pub mod graph;
+pub mod grid;
+pub mod image;
+mod input;
+pub mod math;
+pub mod memory;
+pub mod output;
+pub mod parallel;
+pub mod parse;
+pub mod point;
+pub mod rng;
+mod runner;
+pub mod scan;
+pub mod sha256;
+mod solution;
+pub mod submissions;
+
+pub use animate::{Cell, Color, Frame, Frames};
+pub use answers::{Answers, Expected, Verdict};
+pub use bench::{bench, BenchReport, Stats};
+pub use counter::Counter;
+pub use gif::GifWriter;
+pub use grammar::Parser;
+pub use grid::Grid;
+pub use image::{Image, ImageFormat, Rgb};
+pub use input::{crate_dir, day_dir, InputSource, INPUT_DIR_VAR};
+pub use memory::{allocs, AllocReport, AllocStats};
+pub use output::{Format, Row};
+pub use parse::ParseError;
+pub use point::{Dir4, Dir8, Point, Vec2};
+pub use rng::Rng;
+pub use runner::{run, Part};
+pub use scan::Scanner;
+pub use solution::{DynSolution, Solution, Year};
+pub use submissions::{Feedback, Submission, SubmissionLog};
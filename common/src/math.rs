@@ -0,0 +1,336 @@
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
+/// The primitive integers the number theory here works on.
+pub trait Integer:
+    Copy
+    + Ord
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+}
+
+/// Integers that go below zero, which Bézout coefficients need.
+pub trait Signed: Integer {}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+
+                fn checked_div(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_div(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_signed {
+    ($($t:ty),*) => {
+        $(
+            impl Signed for $t {}
+        )*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_signed!(i8, i16, i32, i64, i128, isize);
+
+// Each function panics on overflow or on arguments outside what its comment allows; its
+// `checked_` twin returns `None` instead. Where the plain one already returns an `Option`, the
+// checked one's `None` can mean either.
+
+fn checked_abs<T: Integer>(n: T) -> Option<T> {
+    if n < T::ZERO {
+        T::ZERO.checked_sub(n)
+    } else {
+        Some(n)
+    }
+}
+
+/// `n` modulo a positive `m`, never negative.
+fn modulo<T: Integer>(n: T, m: T) -> T {
+    let rem = n % m;
+    if rem < T::ZERO {
+        rem + m
+    } else {
+        rem
+    }
+}
+
+fn checked_pow<T: Integer>(base: T, exp: u32) -> Option<T> {
+    (0..exp).try_fold(T::ONE, |acc, _| acc.checked_mul(base))
+}
+
+/// The greatest common divisor, never negative. `gcd(0, 0)` is zero.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    checked_gcd(a, b).expect("gcd overflowed")
+}
+
+pub fn checked_gcd<T: Integer>(a: T, b: T) -> Option<T> {
+    let (mut a, mut b) = (checked_abs(a)?, checked_abs(b)?);
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+
+    Some(a)
+}
+
+/// The least common multiple, never negative. Zero if either is zero.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    checked_lcm(a, b).expect("lcm overflowed")
+}
+
+pub fn checked_lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+
+    (checked_abs(a)? / checked_gcd(a, b)?).checked_mul(checked_abs(b)?)
+}
+
+/// `(g, x, y)` with `g = gcd(a, b)` and `a * x + b * y = g`.
+pub fn egcd<T: Signed>(a: T, b: T) -> (T, T, T) {
+    checked_egcd(a, b).expect("egcd overflowed")
+}
+
+pub fn checked_egcd<T: Signed>(a: T, b: T) -> Option<(T, T, T)> {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+
+    while r != T::ZERO {
+        let q = old_r.checked_div(r)?;
+        (old_r, r) = (r, old_r.checked_sub(q.checked_mul(r)?)?);
+        (old_x, x) = (x, old_x.checked_sub(q.checked_mul(x)?)?);
+        (old_y, y) = (y, old_y.checked_sub(q.checked_mul(y)?)?);
+    }
+
+    if old_r < T::ZERO {
+        let negate = |n: T| T::ZERO.checked_sub(n);
+        Some((negate(old_r)?, negate(old_x)?, negate(old_y)?))
+    } else {
+        Some((old_r, old_x, old_y))
+    }
+}
+
+/// The `x` in `0..m` with `a * x ≡ 1 (mod m)`, if `a` and `m` are coprime. `m` must be positive.
+pub fn mod_inverse<T: Signed>(a: T, m: T) -> Option<T> {
+    try_mod_inverse(a, m).expect("mod_inverse needs m > 0, or it overflowed")
+}
+
+pub fn checked_mod_inverse<T: Signed>(a: T, m: T) -> Option<T> {
+    try_mod_inverse(a, m).flatten()
+}
+
+/// The outer `None` is a bad argument or overflow, the inner one no answer. The same goes for
+/// the other `try_` functions.
+fn try_mod_inverse<T: Signed>(a: T, m: T) -> Option<Option<T>> {
+    if m <= T::ZERO {
+        return None;
+    }
+    let (g, x, _) = checked_egcd(modulo(a, m), m)?;
+
+    Some((g == T::ONE).then(|| modulo(x, m)))
+}
+
+/// Every `x` with `a * x ≡ b (mod m)`, as the smallest non-negative one and the period they
+/// repeat with. `None` if there are none. `m` must be positive.
+pub fn linear_congruence<T: Signed>(a: T, b: T, m: T) -> Option<(T, T)> {
+    try_linear_congruence(a, b, m).expect("linear_congruence needs m > 0, or it overflowed")
+}
+
+pub fn checked_linear_congruence<T: Signed>(a: T, b: T, m: T) -> Option<(T, T)> {
+    try_linear_congruence(a, b, m).flatten()
+}
+
+fn try_linear_congruence<T: Signed>(a: T, b: T, m: T) -> Option<Option<(T, T)>> {
+    if m <= T::ZERO {
+        return None;
+    }
+    let g = checked_gcd(a, m)?;
+    if modulo(b, g) != T::ZERO {
+        return Some(None);
+    }
+
+    let period = m / g;
+    let inverse = try_mod_inverse(a / g, period)??;
+    let x = inverse.checked_mul(modulo(b / g, period))?;
+
+    Some(Some((modulo(x, period), period)))
+}
+
+/// The `x` meeting every `x ≡ residue (mod modulus)`, as the smallest non-negative one and the
+/// lcm of the moduli it repeats with. Moduli need not be coprime; `None` if the congruences
+/// contradict each other. Moduli must be positive.
+pub fn crt<T: Signed>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    try_crt(congruences).expect("crt needs positive moduli, or it overflowed")
+}
+
+pub fn checked_crt<T: Signed>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    try_crt(congruences).flatten()
+}
+
+fn try_crt<T: Signed>(congruences: &[(T, T)]) -> Option<Option<(T, T)>> {
+    let (mut x, mut m) = (T::ZERO, T::ONE);
+
+    for &(residue, modulus) in congruences {
+        // x + m * k ≡ residue (mod modulus), solved for k.
+        let Some((k, period)) = try_linear_congruence(m, residue.checked_sub(x)?, modulus)? else {
+            return Some(None);
+        };
+        let lcm = m.checked_mul(period)?;
+        x = modulo(x.checked_add(m.checked_mul(k)?)?, lcm);
+        m = lcm;
+    }
+
+    Some(Some((x, m)))
+}
+
+/// How many digits `n` has in `base`; zero has one. `n` must not be negative and `base` must be
+/// at least 2.
+pub fn digit_count<T: Integer>(n: T, base: T) -> u32 {
+    checked_digit_count(n, base).expect("digit_count needs n >= 0 and base >= 2")
+}
+
+pub fn checked_digit_count<T: Integer>(n: T, base: T) -> Option<u32> {
+    if n < T::ZERO || base <= T::ONE {
+        return None;
+    }
+
+    let (mut n, mut count) = (n, 1);
+    while n >= base {
+        n = n / base;
+        count += 1;
+    }
+
+    Some(count)
+}
+
+/// Splits the last `low` digits of `n` in `base` off the ones in front: 1234 split at 1 is
+/// (123, 4). `n` must not be negative and `base` must be at least 2.
+pub fn split_digits<T: Integer>(n: T, low: u32, base: T) -> (T, T) {
+    checked_split_digits(n, low, base).expect("split_digits needs n >= 0 and base >= 2")
+}
+
+pub fn checked_split_digits<T: Integer>(n: T, low: u32, base: T) -> Option<(T, T)> {
+    if n < T::ZERO || base <= T::ONE {
+        return None;
+    }
+
+    // A divisor too big to hold is bigger than `n`, so every digit is a low one.
+    Some(match checked_pow(base, low) {
+        Some(divisor) => (n / divisor, n % divisor),
+        None => (T::ZERO, n),
+    })
+}
+
+/// The digits of `a` followed by those of `b` in `base`: 12 and 345 make 12345. Neither may be
+/// negative and `base` must be at least 2.
+pub fn concat<T: Integer>(a: T, b: T, base: T) -> T {
+    checked_concat(a, b, base).expect("concat needs a, b >= 0 and base >= 2, or it overflowed")
+}
+
+pub fn checked_concat<T: Integer>(a: T, b: T, base: T) -> Option<T> {
+    if a < T::ZERO {
+        return None;
+    }
+
+    checked_pow(base, checked_digit_count(b, base)?)?
+        .checked_mul(a)?
+        .checked_add(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12i32, 18), 6);
+        assert_eq!(gcd(0u8, 0), 0);
+        assert_eq!(lcm(4, -6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(checked_lcm(1u8 << 7, 3), None);
+        assert_eq!(checked_gcd(i8::MIN, 0), None);
+
+        for (a, b) in [(240, 46), (-240, 46), (7, 0), (0, -7), (17, 5)] {
+            let (g, x, y) = egcd(a, b);
+            assert_eq!(g, gcd(a, b));
+            assert_eq!(a * x + b * y, g);
+        }
+        assert_eq!(checked_egcd(i8::MIN, -1), None);
+    }
+
+    #[test]
+    fn test_inverse() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(checked_mod_inverse(3, 0), None);
+        assert_eq!(checked_mod_inverse(3i8, 127), Some(85));
+
+        assert_eq!(linear_congruence(6, 4, 10), Some((4, 5)));
+        assert_eq!(linear_congruence(6, 3, 10), None);
+        assert_eq!(linear_congruence(0, 0, 7), Some((0, 1)));
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(-1, 101), (5, 103)]), Some((10099, 10403)));
+        assert_eq!(crt(&[(3, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(crt(&[(3, 4), (4, 6)]), None);
+        assert_eq!(crt::<i64>(&[]), Some((0, 1)));
+
+        assert_eq!(checked_crt(&[(1i8, 11), (2, 13)]), None);
+        assert_eq!(checked_crt(&[(1i64, 11), (2, 13)]), Some((67, 143)));
+    }
+
+    #[test]
+    fn test_digits() {
+        assert_eq!(digit_count(0u32, 10), 1);
+        assert_eq!(digit_count(1000u32, 10), 4);
+        assert_eq!(digit_count(255u8, 2), 8);
+        assert_eq!(digit_count(u64::MAX, 10), 20);
+
+        assert_eq!(split_digits(253000u64, 3, 10), (253, 0));
+        assert_eq!(split_digits(0xabcdu32, 1, 16), (0xabc, 0xd));
+        assert_eq!(split_digits(u64::MAX, 20, 10), (0, u64::MAX));
+        assert_eq!(checked_digit_count(5u8, 1), None);
+        assert_eq!(checked_digit_count(-5, 10), None);
+        assert_eq!(checked_split_digits(-15, 1, 10), None);
+        assert_eq!(checked_split_digits(15, 1, 0), None);
+
+        assert_eq!(concat(12u64, 345, 10), 12345);
+        assert_eq!(concat(12u64, 0, 10), 120);
+        assert_eq!(concat(0b101u8, 0b11, 2), 0b10111);
+        assert_eq!(checked_concat(25u8, 6, 10), None);
+        assert_eq!(checked_concat(-1, 6, 10), None);
+    }
+}
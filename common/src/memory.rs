@@ -0,0 +1,239 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+use crate::{ParseError, Solution};
+
+static BYTES: AtomicU64 = AtomicU64::new(0);
+static COUNT: AtomicU64 = AtomicU64::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// Whether this build counts allocations at all; without the `alloc-stats` feature every
+/// measurement comes back as zero.
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+/// The system allocator, keeping process-wide totals of what goes through it.
+pub struct CountingAllocator;
+
+fn allocated(size: usize) {
+    BYTES.fetch_add(size as u64, Ordering::Relaxed);
+    COUNT.fetch_add(1, Ordering::Relaxed);
+    let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(live, Ordering::Relaxed);
+}
+
+fn freed(size: usize) {
+    LIVE.fetch_sub(size, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        freed(layout.size());
+    }
+
+    /// A growing `Vec` pays for the whole new buffer each time, so that is what gets counted.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            freed(layout.size());
+            allocated(new_size);
+        }
+
+        new
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AllocStats {
+    /// Everything allocated, including what was freed again.
+    pub bytes: u64,
+    pub count: u64,
+    /// The most memory live at once on top of what was live before.
+    pub peak: usize,
+}
+
+impl AllocStats {
+    fn to_json(self) -> String {
+        format!(
+            "{{\"bytes\":{},\"count\":{},\"peak_bytes\":{}}}",
+            self.bytes, self.count, self.peak
+        )
+    }
+}
+
+/// Runs `f` and reports what it allocated. The counters are shared by every thread, so work
+/// handed to `parallel` is counted too, and so is anything running alongside.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+    let (bytes, count) = (BYTES.load(Ordering::Relaxed), COUNT.load(Ordering::Relaxed));
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+
+    let result = f();
+
+    let stats = AllocStats {
+        bytes: BYTES.load(Ordering::Relaxed) - bytes,
+        count: COUNT.load(Ordering::Relaxed) - count,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+    };
+
+    (result, stats)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllocReport {
+    pub year: u16,
+    pub day: u8,
+    pub process: AllocStats,
+    pub part1: AllocStats,
+    pub part2: AllocStats,
+}
+
+impl AllocReport {
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"day\":{},\"process\":{},\"part1\":{},\"part2\":{},\"year\":{}}}",
+            self.day,
+            self.process.to_json(),
+            self.part1.to_json(),
+            self.part2.to_json(),
+            self.year
+        )
+    }
+}
+
+fn human_bytes(bytes: u64) -> String {
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    for unit in ["KiB", "MiB"] {
+        if size < 1024.0 {
+            return format!("{:.1} {}", size, unit);
+        }
+        size /= 1024.0;
+    }
+
+    format!("{:.1} GiB", size)
+}
+
+impl fmt::Display for AllocReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<8}{:>14}{:>14}{:>14}",
+            "", "allocated", "allocations", "peak"
+        )?;
+        for (name, stats) in [
+            ("process", self.process),
+            ("part 1", self.part1),
+            ("part 2", self.part2),
+        ] {
+            writeln!(
+                f,
+                "{:<8}{:>14}{:>14}{:>14}",
+                name,
+                human_bytes(stats.bytes),
+                stats.count,
+                human_bytes(stats.peak as u64)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Measures `process`, `part1` and `part2` once each. Part answers are turned into strings as
+/// part of their part, the way `run` does.
+pub fn allocs<S: Solution>(input: &str) -> Result<AllocReport, ParseError> {
+    let (processed, process) = measure(|| S::process(input));
+    let processed = processed.map_err(|err| err.with_day(S::DAY))?;
+
+    Ok(AllocReport {
+        year: S::YEAR,
+        day: S::DAY,
+        process,
+        part1: measure(|| S::part1(&processed).to_string()).1,
+        part2: measure(|| S::part2(&processed).to_string()).1,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_human_bytes() {
+        assert_eq!(human_bytes(0), "0 B");
+        assert_eq!(human_bytes(1023), "1023 B");
+        assert_eq!(human_bytes(1536), "1.5 KiB");
+        assert_eq!(human_bytes(3 << 30), "3.0 GiB");
+        assert_eq!(human_bytes(5 << 40), "5120.0 GiB");
+    }
+
+    #[test]
+    fn test_report_json() {
+        let stats = AllocStats {
+            bytes: 2048,
+            count: 3,
+            peak: 1024,
+        };
+        let report = AllocReport {
+            year: 2024,
+            day: 11,
+            process: stats,
+            part1: stats,
+            part2: stats,
+        };
+
+        let json = report.to_json();
+        assert!(json.starts_with("{\"day\":11,\"process\":{"));
+        assert!(json.ends_with(",\"year\":2024}"));
+    }
+
+    #[cfg(feature = "alloc-stats")]
+    #[test]
+    fn test_measure() {
+        let (_, stats) = measure(|| {
+            let mut v: Vec<u64> = Vec::with_capacity(1000);
+            v.extend(0..1000);
+            drop(v);
+            vec![0u8; 100]
+        });
+
+        // Other tests allocate and free on their own threads meanwhile, so only the totals,
+        // which never go down, can be pinned.
+        assert!(stats.count >= 2);
+        assert!(stats.bytes >= 8100);
+    }
+
+    #[cfg(not(feature = "alloc-stats"))]
+    #[test]
+    fn test_measure_disabled() {
+        assert_eq!(measure(|| vec![0u8; 100]).1, AllocStats::default());
+    }
+}
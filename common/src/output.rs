@@ -0,0 +1,214 @@
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::sha256::sha256_hex;
+use crate::{ParseError, Part, Solution};
+
+/// How `aoc run` prints results. Everything but `Human` prints one row per day and part with
+/// these fields, in this order:
+///
+/// | field          | meaning                                                  |
+/// |----------------|----------------------------------------------------------|
+/// | `day`          | day number                                               |
+/// | `part`         | 1 or 2                                                   |
+/// | `answer`       | the answer as it would be submitted                      |
+/// | `input_sha256` | lowercase hex SHA-256 of the input bytes                 |
+/// | `process_ns`   | time spent in `process`, shared by both parts of a day   |
+/// | `part_ns`      | time spent in this part                                  |
+/// | `year`         | the event the day belongs to                             |
+///
+/// Fields are only ever added at the end, so consumers should look them up by name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Human,
+    /// One JSON object per line.
+    Json,
+    /// A header line, then tab-separated rows. Tabs and newlines in answers are escaped as `\t`
+    /// and `\n`.
+    Tsv,
+    /// A header line, then RFC 4180 rows.
+    Csv,
+}
+
+pub const FIELDS: [&str; 7] = [
+    "day",
+    "part",
+    "answer",
+    "input_sha256",
+    "process_ns",
+    "part_ns",
+    "year",
+];
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Format::Human),
+            "json" => Ok(Format::Json),
+            "tsv" => Ok(Format::Tsv),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "invalid format {:?}, expected human, json, tsv or csv",
+                s
+            )),
+        }
+    }
+}
+
+/// One part's result, with what it took to get there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub input_sha256: String,
+    pub process: Duration,
+    pub elapsed: Duration,
+}
+
+impl Format {
+    /// The line to print before any rows, if the format has one.
+    pub fn header(self) -> Option<String> {
+        match self {
+            Format::Human | Format::Json => None,
+            Format::Tsv => Some(FIELDS.join("\t")),
+            Format::Csv => Some(FIELDS.join(",")),
+        }
+    }
+
+    pub fn row(self, row: &Row) -> String {
+        let values = |answer: String, input_sha256: String| {
+            [
+                row.day.to_string(),
+                row.part.to_string(),
+                answer,
+                input_sha256,
+                row.process.as_nanos().to_string(),
+                row.elapsed.as_nanos().to_string(),
+                row.year.to_string(),
+            ]
+        };
+
+        match self {
+            Format::Human => format!(
+                "{} day {} part {}: {}",
+                row.year, row.day, row.part, row.answer
+            ),
+            Format::Json => {
+                let values = values(json_string(&row.answer), json_string(&row.input_sha256));
+                let fields: Vec<String> = FIELDS
+                    .iter()
+                    .zip(values)
+                    .map(|(name, value)| format!("\"{}\":{}", name, value))
+                    .collect();
+
+                format!("{{{}}}", fields.join(","))
+            }
+            Format::Tsv => {
+                let answer = row.answer.replace('\t', "\\t").replace('\n', "\\n");
+                values(answer, row.input_sha256.clone()).join("\t")
+            }
+            Format::Csv => {
+                let answer = if row.answer.contains([',', '"', '\n', '\r']) {
+                    format!("\"{}\"", row.answer.replace('"', "\"\""))
+                } else {
+                    row.answer.clone()
+                };
+                values(answer, row.input_sha256.clone()).join(",")
+            }
+        }
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+
+    escaped
+}
+
+/// Solves `parts` once, timing each step, without printing anything.
+pub fn rows<S: Solution>(input: &str, parts: &[Part]) -> Result<Vec<Row>, ParseError> {
+    let input_sha256 = sha256_hex(input.as_bytes());
+
+    let start = Instant::now();
+    let processed = S::process(input).map_err(|err| err.with_day(S::DAY))?;
+    let process = start.elapsed();
+
+    Ok(parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => S::part1(&processed).to_string(),
+                Part::Two => S::part2(&processed).to_string(),
+            };
+
+            Row {
+                year: S::YEAR,
+                day: S::DAY,
+                part,
+                answer,
+                input_sha256: input_sha256.clone(),
+                process,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(answer: &str) -> Row {
+        Row {
+            year: 2024,
+            day: 7,
+            part: Part::Two,
+            answer: answer.to_owned(),
+            input_sha256: "ab12".to_owned(),
+            process: Duration::from_nanos(1500),
+            elapsed: Duration::from_micros(2),
+        }
+    }
+
+    #[test]
+    fn test_formats() {
+        assert_eq!(
+            Format::Json.row(&row("4,2")),
+            "{\"day\":7,\"part\":2,\"answer\":\"4,2\",\"input_sha256\":\"ab12\",\"process_ns\":1500,\"part_ns\":2000,\"year\":2024}"
+        );
+        assert_eq!(
+            Format::Tsv.row(&row("4,2")),
+            "7\t2\t4,2\tab12\t1500\t2000\t2024"
+        );
+        assert_eq!(
+            Format::Csv.row(&row("4,2")),
+            "7,2,\"4,2\",ab12,1500,2000,2024"
+        );
+        assert_eq!(Format::Csv.row(&row("42")), "7,2,42,ab12,1500,2000,2024");
+        assert_eq!(
+            Format::Csv.header().unwrap(),
+            "day,part,answer,input_sha256,process_ns,part_ns,year"
+        );
+    }
+
+    #[test]
+    fn test_json_escapes() {
+        assert_eq!(json_string("a\"b\\c\nd"), "\"a\\\"b\\\\c\\nd\"");
+    }
+}
@@ -0,0 +1,85 @@
+use std::iter::Sum;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+/// 0 means one worker per available core.
+static THREADS: AtomicUsize = AtomicUsize::new(0);
+
+/// Sets how many workers the helpers below use; 0 goes back to one per core.
+pub fn set_threads(threads: usize) {
+    THREADS.store(threads, Ordering::Relaxed);
+}
+
+pub fn threads() -> usize {
+    match THREADS.load(Ordering::Relaxed) {
+        0 => thread::available_parallelism().map_or(1, |n| n.get()),
+        threads => threads,
+    }
+}
+
+/// `f` over every item, on up to `threads()` scoped threads each taking a contiguous chunk.
+/// Results come back in the same order as `items`, however many workers there are.
+pub fn map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let workers = threads().min(items.len());
+    if workers <= 1 {
+        return items.iter().map(f).collect();
+    }
+
+    let chunk_size = items.len().div_ceil(workers);
+    let f = &f;
+    thread::scope(|scope| {
+        let handles: Vec<_> = items
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || chunk.iter().map(f).collect::<Vec<R>>()))
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    })
+}
+
+/// The items `predicate` holds for, in their original order.
+pub fn filter<T: Sync>(items: &[T], predicate: impl Fn(&T) -> bool + Sync) -> Vec<&T> {
+    let keep = map(items, predicate);
+
+    items
+        .iter()
+        .zip(keep)
+        .filter_map(|(item, keep)| keep.then_some(item))
+        .collect()
+}
+
+/// Sums in item order, so even floating point totals do not depend on the worker count.
+pub fn sum<T: Sync, R: Send + Sum<R>>(items: &[T], f: impl Fn(&T) -> R + Sync) -> R {
+    map(items, f).into_iter().sum()
+}
+
+pub fn count<T: Sync>(items: &[T], predicate: impl Fn(&T) -> bool + Sync) -> usize {
+    sum(items, |item| predicate(item) as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order_is_kept() {
+        let items: Vec<u64> = (0..1000).collect();
+
+        for workers in [1, 3, 8, 2000] {
+            set_threads(workers);
+            assert_eq!(
+                map(&items, |x| x * 2),
+                items.iter().map(|x| x * 2).collect::<Vec<_>>()
+            );
+            assert_eq!(filter(&items, |x| x % 7 == 0).len(), 143);
+            assert_eq!(sum(&items, |&x| x), 499500);
+            assert_eq!(count(&items, |&x| x >= 990), 10);
+        }
+        set_threads(0);
+
+        assert!(map(&[] as &[u8], |&x| x).is_empty());
+    }
+}
@@ -0,0 +1,167 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: Option<u8>,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Points at `fragment`, which must be a slice of `input`. Lines and columns are 1-based.
+    pub fn at(input: &str, fragment: &str, message: impl Into<String>) -> Self {
+        let offset = (fragment.as_ptr() as usize)
+            .saturating_sub(input.as_ptr() as usize)
+            .min(input.len());
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        Self {
+            day: None,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: fragment.to_owned(),
+            message: message.into(),
+        }
+    }
+
+    /// Points just past the end of `input`, for sections that are missing entirely.
+    pub fn at_end(input: &str, message: impl Into<String>) -> Self {
+        Self::at(input, &input[input.len()..], message)
+    }
+
+    pub fn with_day(self, day: u8) -> Self {
+        Self {
+            day: Some(day),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {}, ", day)?;
+        }
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        if !self.text.is_empty() {
+            write!(f, ": {:?}", self.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+pub fn number<T: FromStr>(input: &str, raw: &str) -> Result<T, ParseError> {
+    raw.parse()
+        .map_err(|_| ParseError::at(input, raw, "invalid number"))
+}
+
+pub fn split_once<'a>(
+    input: &str,
+    raw: &'a str,
+    delimiter: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    raw.split_once(delimiter)
+        .ok_or_else(|| ParseError::at(input, raw, format!("expected {:?}", delimiter)))
+}
+
+pub fn strip_prefix<'a>(input: &str, raw: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    raw.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::at(input, raw, format!("expected {:?}", prefix)))
+}
+
+/// The lines of a file like `answers.txt`, trimmed, without blank lines and `#` comments. They
+/// are still slices of `input`, so errors can point into it.
+pub fn entries(input: &str) -> impl Iterator<Item = &str> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+}
+
+/// Reads and parses the file at `path`, naming it in any parse error. A missing file is the same
+/// as an empty one.
+pub fn load<T: Default>(
+    path: &Path,
+    parse: impl FnOnce(&str) -> Result<T, ParseError>,
+) -> io::Result<T> {
+    match fs::read_to_string(path) {
+        Ok(text) => parse(&text).map_err(|err| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {}", path.display(), err),
+            )
+        }),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(T::default()),
+        Err(err) => Err(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position() {
+        let input = "12 34\n56 7x\n";
+        let err = number::<u32>(input, &input[9..11]).unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 4));
+        assert_eq!(err.text, "7x");
+        assert_eq!(
+            err.with_day(1).to_string(),
+            "day 1, line 2, column 4: invalid number: \"7x\""
+        );
+    }
+
+    #[test]
+    fn test_at_end() {
+        let err = ParseError::at_end("a\nbc", "missing section");
+
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.to_string(), "line 2, column 3: missing section");
+    }
+
+    #[test]
+    fn test_split_once() {
+        let input = "47|53\n97-13";
+
+        assert_eq!(split_once(input, &input[..5], "|"), Ok(("47", "53")));
+        assert_eq!(split_once(input, &input[6..], "|").unwrap_err().line, 2);
+    }
+
+    #[test]
+    fn test_load() {
+        let path = std::env::temp_dir().join(format!("aoc-parse-{}.txt", std::process::id()));
+        let count = |input: &str| -> Result<usize, ParseError> {
+            entries(input)
+                .map(|line| number::<usize>(input, line))
+                .sum()
+        };
+
+        assert_eq!(load(&path, count).unwrap(), 0);
+
+        fs::write(&path, "# counts\n  1\n\n2\nx\n").unwrap();
+        let err = load(&path, count).unwrap_err();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err
+            .to_string()
+            .ends_with("line 5, column 1: invalid number: \"x\""));
+    }
+}
@@ -0,0 +1,281 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A grid position. Rows grow downwards and columns grow rightwards, so `y` is `row` and `x` is
+/// `col`; construct through `from_row_col` or `from_xy` to keep the two conventions apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Point {
+    pub const fn from_row_col(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    pub const fn from_xy(x: usize, y: usize) -> Self {
+        Self { row: y, col: x }
+    }
+
+    pub const fn x(&self) -> usize {
+        self.col
+    }
+
+    pub const fn y(&self) -> usize {
+        self.row
+    }
+
+    pub fn checked_add(self, delta: impl Into<Vec2>) -> Option<Self> {
+        let delta = delta.into();
+
+        Some(Self {
+            row: self.row.checked_add_signed(delta.row)?,
+            col: self.col.checked_add_signed(delta.col)?,
+        })
+    }
+
+    pub fn checked_sub(self, delta: impl Into<Vec2>) -> Option<Self> {
+        self.checked_add(-delta.into())
+    }
+}
+
+impl Sub for Point {
+    type Output = Vec2;
+
+    fn sub(self, other: Self) -> Vec2 {
+        Vec2 {
+            row: self.row as isize - other.row as isize,
+            col: self.col as isize - other.col as isize,
+        }
+    }
+}
+
+/// A signed offset between two `Point`s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Vec2 {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Vec2 {
+    pub const fn from_row_col(row: isize, col: isize) -> Self {
+        Self { row, col }
+    }
+
+    pub const fn from_xy(x: isize, y: isize) -> Self {
+        Self { row: y, col: x }
+    }
+
+    pub const fn x(&self) -> isize {
+        self.col
+    }
+
+    pub const fn y(&self) -> isize {
+        self.row
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::from_row_col(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::from_row_col(-self.row, -self.col)
+    }
+}
+
+impl Mul<isize> for Vec2 {
+    type Output = Self;
+
+    fn mul(self, factor: isize) -> Self {
+        Self::from_row_col(self.row * factor, self.col * factor)
+    }
+}
+
+/// The four orthogonal directions, in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Dir4::Up | Dir4::Down)
+    }
+
+    /// Parses the `^`, `>`, `v` and `<` arrows.
+    pub fn from_glyph(glyph: char) -> Option<Self> {
+        match glyph {
+            '^' => Some(Dir4::Up),
+            '>' => Some(Dir4::Right),
+            'v' => Some(Dir4::Down),
+            '<' => Some(Dir4::Left),
+            _ => None,
+        }
+    }
+
+    pub fn glyph(self) -> char {
+        match self {
+            Dir4::Up => '^',
+            Dir4::Right => '>',
+            Dir4::Down => 'v',
+            Dir4::Left => '<',
+        }
+    }
+}
+
+impl From<Dir4> for Vec2 {
+    fn from(dir: Dir4) -> Self {
+        match dir {
+            Dir4::Up => Vec2::from_row_col(-1, 0),
+            Dir4::Right => Vec2::from_row_col(0, 1),
+            Dir4::Down => Vec2::from_row_col(1, 0),
+            Dir4::Left => Vec2::from_row_col(0, -1),
+        }
+    }
+}
+
+/// The orthogonal and diagonal directions, in clockwise order starting at `Up`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    /// Turns an eighth of a circle clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns an eighth of a circle counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Self::ALL[dir as usize * 2]
+    }
+}
+
+impl From<Dir8> for Vec2 {
+    fn from(dir: Dir8) -> Self {
+        match dir {
+            Dir8::Up => Vec2::from_row_col(-1, 0),
+            Dir8::UpRight => Vec2::from_row_col(-1, 1),
+            Dir8::Right => Vec2::from_row_col(0, 1),
+            Dir8::DownRight => Vec2::from_row_col(1, 1),
+            Dir8::Down => Vec2::from_row_col(1, 0),
+            Dir8::DownLeft => Vec2::from_row_col(1, -1),
+            Dir8::Left => Vec2::from_row_col(0, -1),
+            Dir8::UpLeft => Vec2::from_row_col(-1, -1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conventions() {
+        let point = Point::from_xy(3, 5);
+
+        assert_eq!(point, Point::from_row_col(5, 3));
+        assert_eq!((point.x(), point.y()), (3, 5));
+        assert_eq!(Vec2::from(Dir4::Right), Vec2::from_xy(1, 0));
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let origin = Point::from_row_col(0, 0);
+
+        assert_eq!(origin.checked_add(Dir4::Up), None);
+        assert_eq!(
+            origin.checked_sub(Dir4::Up),
+            Some(Point::from_row_col(1, 0))
+        );
+        assert_eq!(
+            origin.checked_add(Vec2::from_row_col(2, 3)),
+            Some(Point::from_row_col(2, 3))
+        );
+        assert_eq!(
+            Point::from_row_col(1, 4) - Point::from_row_col(3, 2),
+            Vec2::from_row_col(-2, 2)
+        );
+    }
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Dir4::Up.turn_right(), Dir4::Right);
+        assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+        assert_eq!(Dir4::Left.opposite(), Dir4::Right);
+        assert_eq!(Dir8::UpLeft.turn_right(), Dir8::Up);
+        assert_eq!(Dir8::Up.turn_left(), Dir8::UpLeft);
+        assert_eq!(Dir8::DownRight.opposite(), Dir8::UpLeft);
+        assert_eq!(Dir8::from(Dir4::Down), Dir8::Down);
+    }
+
+    #[test]
+    fn test_glyphs() {
+        for dir in Dir4::ALL {
+            assert_eq!(Dir4::from_glyph(dir.glyph()), Some(dir));
+        }
+        assert_eq!(Dir4::from_glyph('x'), None);
+    }
+}
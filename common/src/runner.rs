@@ -0,0 +1,68 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("invalid part {:?}, expected 1 or 2", s)),
+        }
+    }
+}
+
+/// Prints each part's result as it is computed, and returns them all.
+pub fn run<S: Solution>(input: &str, parts: &[Part]) -> Result<Vec<(Part, String)>, ParseError> {
+    println!("Processing input");
+    let input = S::process(input).map_err(|err| err.with_day(S::DAY))?;
+
+    let mut results = vec![];
+    for &part in parts {
+        println!("------");
+
+        println!("Running part {}", part);
+        let result = match part {
+            Part::One => S::part1(&input).to_string(),
+            Part::Two => S::part2(&input).to_string(),
+        };
+        println!("Result: {}", result);
+        results.push((part, result));
+    }
+
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_part() {
+        assert_eq!("1".parse(), Ok(Part::One));
+        assert_eq!("2".parse(), Ok(Part::Two));
+        assert!("3".parse::<Part>().is_err());
+    }
+}
@@ -0,0 +1,350 @@
+use std::iter;
+use std::marker::PhantomData;
+
+use crate::ParseError;
+
+/// The primitive integers a `Scanner` can read, digit by digit and without going through `str`.
+pub trait Int: Copy + Default {
+    const SIGNED: bool;
+
+    /// `self * 10 ± digit`, or `None` on overflow.
+    fn push_digit(self, digit: u8, negative: bool) -> Option<Self>;
+}
+
+macro_rules! impl_int {
+    ($($t:ty: $signed:expr),*) => {
+        $(
+            impl Int for $t {
+                const SIGNED: bool = $signed;
+
+                fn push_digit(self, digit: u8, negative: bool) -> Option<Self> {
+                    let shifted = self.checked_mul(10)?;
+                    if negative {
+                        shifted.checked_sub(digit as $t)
+                    } else {
+                        shifted.checked_add(digit as $t)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_int!(
+    u8: false, u16: false, u32: false, u64: false, u128: false, usize: false,
+    i8: true, i16: true, i32: true, i64: true, i128: true, isize: true
+);
+
+/// A cursor over part of an input, reading numbers and literals in place. Errors point into the
+/// whole input, however small the part being scanned.
+#[derive(Debug, Clone)]
+pub struct Scanner<'a> {
+    input: &'a str,
+    pos: usize,
+    end: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            pos: 0,
+            end: input.len(),
+        }
+    }
+
+    /// Scans just `fragment`, which must be a slice of `input`.
+    pub fn within(input: &'a str, fragment: &'a str) -> Self {
+        let pos = (fragment.as_ptr() as usize)
+            .saturating_sub(input.as_ptr() as usize)
+            .min(input.len());
+
+        Self {
+            input,
+            pos,
+            end: (pos + fragment.len()).min(input.len()),
+        }
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.input[self.pos..self.end]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos >= self.end
+    }
+
+    pub fn peek(&self) -> Option<u8> {
+        self.rest().bytes().next()
+    }
+
+    fn error(&self, from: usize, to: usize, message: impl Into<String>) -> ParseError {
+        ParseError::at(self.input, &self.input[from..to], message)
+    }
+
+    fn find_from(&self, from: usize, stop: impl Fn(u8) -> bool) -> usize {
+        self.input.as_bytes()[from..self.end]
+            .iter()
+            .position(|&byte| stop(byte))
+            .map_or(self.end, |i| from + i)
+    }
+
+    fn line_end(&self) -> usize {
+        self.find_from(self.pos, |byte| byte == b'\n')
+    }
+
+    /// An error pointing at the rest of the current line.
+    pub fn fail(&self, message: impl Into<String>) -> ParseError {
+        self.error(self.pos, self.line_end(), message)
+    }
+
+    /// Skips spaces and tabs, but not newlines.
+    pub fn skip_spaces(&mut self) {
+        self.pos = self.find_from(self.pos, |byte| byte != b' ' && byte != b'\t');
+    }
+
+    pub fn expect_literal(&mut self, literal: &str) -> Result<(), ParseError> {
+        if !self.rest().starts_with(literal) {
+            return Err(self.fail(format!("expected {:?}", literal)));
+        }
+        self.pos += literal.len();
+
+        Ok(())
+    }
+
+    /// Moves up to the next `needle`, leaving it to be read, and returns what was skipped.
+    pub fn skip_until(&mut self, needle: &str) -> Result<&'a str, ParseError> {
+        let skipped = self
+            .rest()
+            .find(needle)
+            .map(|i| &self.rest()[..i])
+            .ok_or_else(|| self.error(self.end, self.end, format!("expected {:?}", needle)))?;
+        self.pos += skipped.len();
+
+        Ok(skipped)
+    }
+
+    /// Skips spaces, then fails unless nothing is left.
+    pub fn expect_end(&mut self) -> Result<(), ParseError> {
+        self.skip_spaces();
+        if !self.is_empty() {
+            return Err(self.fail("unexpected text"));
+        }
+
+        Ok(())
+    }
+
+    /// Skips spaces, then reads digits.
+    pub fn next_uint<T: Int>(&mut self) -> Result<T, ParseError> {
+        self.skip_spaces();
+        self.number(false)
+    }
+
+    /// Skips spaces, then reads digits with an optional `-` or `+` in front.
+    pub fn next_int<T: Int>(&mut self) -> Result<T, ParseError> {
+        self.skip_spaces();
+        self.number(true)
+    }
+
+    fn number<T: Int>(&mut self, signed: bool) -> Result<T, ParseError> {
+        let start = self.pos;
+        let negative = signed && self.peek() == Some(b'-');
+        if signed && matches!(self.peek(), Some(b'-' | b'+')) {
+            self.pos += 1;
+        }
+
+        let digits = self.pos;
+        let (mut value, mut overflow) = (T::default(), false);
+        while let Some(digit @ b'0'..=b'9') = self.peek() {
+            match value.push_digit(digit - b'0', negative) {
+                Some(next) => value = next,
+                None => overflow = true,
+            }
+            self.pos += 1;
+        }
+
+        if self.pos == digits {
+            self.pos = start;
+            let token_end = self.find_from(start, |byte| byte.is_ascii_whitespace());
+            return Err(self.error(start, token_end, "expected a number"));
+        }
+        if overflow {
+            return Err(self.error(start, self.pos, "number out of range"));
+        }
+
+        Ok(value)
+    }
+
+    /// The next line, without its newline, as a scanner of its own.
+    pub fn next_line(&mut self) -> Option<Scanner<'a>> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let end = self.line_end();
+        let line = Scanner {
+            input: self.input,
+            pos: self.pos,
+            end,
+        };
+        self.pos = (end + 1).min(self.end);
+
+        Some(line)
+    }
+
+    /// The text up to the next blank line, as a scanner of its own. The blank line is skipped.
+    pub fn next_section(&mut self) -> Option<Scanner<'a>> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let len = self.rest().find("\n\n").unwrap_or(self.end - self.pos);
+        let section = Scanner {
+            input: self.input,
+            pos: self.pos,
+            end: self.pos + len,
+        };
+        self.pos = (self.pos + len + 2).min(self.end);
+
+        Some(section)
+    }
+
+    pub fn lines(mut self) -> impl Iterator<Item = Scanner<'a>> {
+        iter::from_fn(move || self.next_line())
+    }
+
+    /// Every integer left, signed ones taking a `-` or `+` right in front of them. Everything
+    /// between them is skipped, so `p=0,4 v=3,-3` gives 0, 4, 3 and -3.
+    pub fn ints<T: Int>(self) -> Ints<'a, T> {
+        Ints {
+            scanner: self,
+            kind: PhantomData,
+        }
+    }
+}
+
+pub struct Ints<'a, T> {
+    scanner: Scanner<'a>,
+    kind: PhantomData<T>,
+}
+
+impl<T: Int> Iterator for Ints<'_, T> {
+    type Item = Result<T, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let scanner = &mut self.scanner;
+        let bytes = scanner.input.as_bytes();
+
+        while !scanner.is_empty() {
+            let byte = bytes[scanner.pos];
+            let signed_digit = T::SIGNED
+                && matches!(byte, b'-' | b'+')
+                && bytes[scanner.pos + 1..scanner.end]
+                    .first()
+                    .is_some_and(u8::is_ascii_digit);
+
+            if byte.is_ascii_digit() || signed_digit {
+                return Some(scanner.number(T::SIGNED));
+            }
+            scanner.pos += 1;
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbers() {
+        let mut scanner = Scanner::new("  12 -34 +5 x");
+
+        assert_eq!(scanner.next_uint::<u8>(), Ok(12));
+        assert_eq!(scanner.next_int::<i32>(), Ok(-34));
+        assert_eq!(scanner.next_int::<u64>(), Ok(5));
+
+        let err = scanner.next_uint::<u8>().unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (13, "x"));
+        assert_eq!(scanner.rest(), "x");
+    }
+
+    #[test]
+    fn test_range() {
+        assert_eq!(Scanner::new("-128").next_int::<i8>(), Ok(-128));
+        assert_eq!(
+            Scanner::new("128").next_int::<i8>().unwrap_err().message,
+            "number out of range"
+        );
+        assert!(Scanner::new("-1").next_int::<u8>().is_err());
+        assert!(Scanner::new("-1").next_uint::<i8>().is_err());
+    }
+
+    #[test]
+    fn test_literals() {
+        let input = "header\nButton A: X+94, Y+34";
+        let mut scanner = Scanner::new(input);
+
+        assert_eq!(scanner.skip_until("Button"), Ok("header\n"));
+        scanner.expect_literal("Button A: X+").unwrap();
+        assert_eq!(scanner.next_uint::<u32>(), Ok(94));
+
+        let err = scanner.expect_literal(" Y+").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 15));
+        assert_eq!(err.message, "expected \" Y+\"");
+        assert_eq!(err.text, ", Y+34");
+
+        assert!(scanner.skip_until("Prize").is_err());
+        assert!(scanner.expect_end().is_err());
+    }
+
+    #[test]
+    fn test_lines() {
+        let input = "x\n1 2\n\n3 4\n";
+        let lines: Vec<_> = Scanner::within(input, &input[2..])
+            .lines()
+            .map(|line| line.rest())
+            .collect();
+        assert_eq!(lines, ["1 2", "", "3 4"]);
+
+        let mut line = Scanner::within(input, &input[7..10]).next_line().unwrap();
+        assert_eq!(line.next_uint::<u8>(), Ok(3));
+        assert_eq!(line.next_uint::<u8>(), Ok(4));
+        let err = line.next_uint::<u8>().unwrap_err();
+        assert_eq!((err.line, err.column), (4, 4));
+    }
+
+    #[test]
+    fn test_sections() {
+        let mut scanner = Scanner::new("a\nb\n\nc\n\n\nd");
+
+        assert_eq!(scanner.next_section().unwrap().rest(), "a\nb");
+        assert_eq!(scanner.next_section().unwrap().rest(), "c");
+        assert_eq!(scanner.next_section().unwrap().rest(), "\nd");
+        assert!(scanner.next_section().is_none());
+    }
+
+    #[test]
+    fn test_ints() {
+        let ints = |line: &str| {
+            Scanner::new(line)
+                .ints::<i64>()
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        assert_eq!(ints("p=0,4 v=3,-3"), Ok(vec![0, 4, 3, -3]));
+        assert_eq!(ints("Button A: X+94, Y+34"), Ok(vec![94, 34]));
+        assert_eq!(ints("Prize: X=8400, Y=5400"), Ok(vec![8400, 5400]));
+        assert_eq!(ints("0,4 3,-3 -"), Ok(vec![0, 4, 3, -3]));
+        assert_eq!(ints("no numbers"), Ok(vec![]));
+        assert_eq!(ints("75|47: 1 +2"), Ok(vec![75, 47, 1, 2]));
+        assert_eq!(
+            Scanner::new("3-4")
+                .ints::<u8>()
+                .collect::<Result<Vec<_>, _>>(),
+            Ok(vec![3, 4])
+        );
+        assert!(ints("99999999999999999999").is_err());
+    }
+}
@@ -0,0 +1,121 @@
+use crate::bench::{bench, BenchReport};
+use crate::memory::{allocs, AllocReport};
+use crate::output::{rows, Row};
+use crate::runner::{run, Part};
+use crate::{Frames, Image, ParseError, Rng};
+
+/// A single day's puzzle: the input is processed once and then shared by both parts.
+pub trait Solution {
+    type Input;
+    const YEAR: u16;
+    const DAY: u8;
+
+    fn process(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> impl ToString;
+    fn part2(input: &Self::Input) -> impl ToString;
+
+    /// A random input that `process` accepts, for stress and scale testing. What `size` counts
+    /// (lines, digits, grid side...) is up to each day.
+    fn generate(_rng: &mut Rng, _size: usize) -> Option<String> {
+        None
+    }
+
+    /// The simulation behind `part`, one frame per step, for days that have one to watch.
+    fn frames(_input: Self::Input, _part: Part) -> Option<Frames> {
+        None
+    }
+
+    /// A picture of the input or of how `part` sees it, one pixel per cell.
+    fn image(_input: &Self::Input, _part: Part) -> Option<Image> {
+        None
+    }
+
+    /// Applies an example file's `param NAME = VALUE` line, for puzzles whose examples differ
+    /// from the real thing in more than the input, like a smaller grid.
+    fn set_param(_input: &mut Self::Input, name: &str, _value: &str) -> Result<(), String> {
+        Err(format!("unknown parameter {:?}", name))
+    }
+}
+
+/// Object-safe view of a `Solution`, so days can be picked at runtime.
+pub trait DynSolution: Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Vec<(Part, String)>, ParseError>;
+    fn bench(&self, input: &str, iterations: usize) -> Result<BenchReport, ParseError>;
+    fn allocs(&self, input: &str) -> Result<AllocReport, ParseError>;
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Vec<(Part, String)>, ParseError>;
+    fn rows(&self, input: &str, parts: &[Part]) -> Result<Vec<Row>, ParseError>;
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String>;
+    fn frames(&self, input: &str, part: Part) -> Result<Option<Frames>, ParseError>;
+    fn image(&self, input: &str, part: Part) -> Result<Option<Image>, ParseError>;
+}
+
+impl<S: Solution + Sync> DynSolution for S {
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Vec<(Part, String)>, ParseError> {
+        run::<S>(input, parts)
+    }
+
+    fn bench(&self, input: &str, iterations: usize) -> Result<BenchReport, ParseError> {
+        bench::<S>(input, iterations)
+    }
+
+    fn allocs(&self, input: &str) -> Result<AllocReport, ParseError> {
+        allocs::<S>(input)
+    }
+
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Vec<(Part, String)>, ParseError> {
+        let input = S::process(input).map_err(|err| err.with_day(S::DAY))?;
+
+        Ok(parts
+            .iter()
+            .map(|&part| match part {
+                Part::One => (part, S::part1(&input).to_string()),
+                Part::Two => (part, S::part2(&input).to_string()),
+            })
+            .collect())
+    }
+
+    fn rows(&self, input: &str, parts: &[Part]) -> Result<Vec<Row>, ParseError> {
+        rows::<S>(input, parts)
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        S::generate(rng, size)
+    }
+
+    fn frames(&self, input: &str, part: Part) -> Result<Option<Frames>, ParseError> {
+        let input = S::process(input).map_err(|err| err.with_day(S::DAY))?;
+
+        Ok(S::frames(input, part))
+    }
+
+    fn image(&self, input: &str, part: Part) -> Result<Option<Image>, ParseError> {
+        let input = S::process(input).map_err(|err| err.with_day(S::DAY))?;
+
+        Ok(S::image(&input, part))
+    }
+}
+
+/// One event's days, listed by that year's crate so the runner can find them.
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [&'static dyn DynSolution],
+}
+
+impl Year {
+    pub fn find(&self, day: u8) -> Option<&'static dyn DynSolution> {
+        self.days
+            .iter()
+            .find(|solution| solution.day() == day)
+            .copied()
+    }
+}
@@ -0,0 +1,184 @@
+use std::fmt;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::Path;
+
+use crate::{parse, Expected, ParseError, Part};
+
+/// The site's verdict on a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feedback {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl fmt::Display for Feedback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Feedback::Correct => write!(f, "correct"),
+            Feedback::TooHigh => write!(f, "too_high"),
+            Feedback::TooLow => write!(f, "too_low"),
+            Feedback::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+/// One attempt. Correct answers are stored salted and hashed, like in `answers.txt`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub part: Part,
+    pub feedback: Feedback,
+    pub answer: Expected,
+}
+
+impl Submission {
+    pub fn new(part: Part, feedback: Feedback, answer: &str) -> Self {
+        let answer = match feedback {
+            Feedback::Correct => Expected::hashed(answer),
+            _ => Expected::Plain(answer.to_owned()),
+        };
+
+        Self {
+            part,
+            feedback,
+            answer,
+        }
+    }
+}
+
+impl fmt::Display for Submission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "part{} {} {}", self.part, self.feedback, self.answer)
+    }
+}
+
+/// A day's `submissions.txt`: one `partN FEEDBACK ANSWER` line per attempt, `#` comments.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SubmissionLog {
+    pub submissions: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut submissions = vec![];
+
+        for line in parse::entries(input) {
+            let (part, rest) = parse::split_once(input, line, " ")?;
+            let (feedback, answer) = parse::split_once(input, rest.trim_start(), " ")?;
+            let part = match part {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                _ => return Err(ParseError::at(input, part, "expected part1 or part2")),
+            };
+            let feedback = match feedback {
+                "correct" => Feedback::Correct,
+                "too_high" => Feedback::TooHigh,
+                "too_low" => Feedback::TooLow,
+                "wrong" => Feedback::Wrong,
+                _ => return Err(ParseError::at(input, feedback, "unknown feedback")),
+            };
+            let answer = answer.trim();
+            let answer = Expected::parse(input, answer)?;
+
+            submissions.push(Submission {
+                part,
+                feedback,
+                answer,
+            });
+        }
+
+        Ok(Self { submissions })
+    }
+
+    /// A missing file is the same as an empty one.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        parse::load(path, Self::parse)
+    }
+
+    /// Adds `submission` to the log and to the end of the file at `path`.
+    pub fn append(&mut self, path: &Path, submission: Submission) -> io::Result<()> {
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{}", submission)?;
+        self.submissions.push(submission);
+
+        Ok(())
+    }
+
+    /// Why `answer` cannot be right, going by earlier attempts at `part`.
+    pub fn known_wrong(&self, part: Part, answer: &str) -> Option<String> {
+        let attempts = || self.submissions.iter().filter(|s| s.part == part);
+
+        if let Some(earlier) =
+            attempts().find(|s| s.feedback != Feedback::Correct && s.answer.matches(answer))
+        {
+            return Some(format!(
+                "{} was already rejected as {}",
+                answer, earlier.feedback
+            ));
+        }
+        if attempts().any(|s| s.feedback == Feedback::Correct && !s.answer.matches(answer)) {
+            return Some(format!("{} is not the answer that was accepted", answer));
+        }
+
+        let value: i128 = answer.parse().ok()?;
+        let bound = |feedback| {
+            attempts()
+                .filter(move |s| s.feedback == feedback)
+                .filter_map(|s| match &s.answer {
+                    Expected::Plain(answer) => answer.parse::<i128>().ok(),
+                    Expected::Hashed { .. } => None,
+                })
+        };
+        if let Some(low) = bound(Feedback::TooLow).max().filter(|&low| value <= low) {
+            return Some(format!(
+                "{} is not above {}, which was too low",
+                answer, low
+            ));
+        }
+        if let Some(high) = bound(Feedback::TooHigh).min().filter(|&high| value >= high) {
+            return Some(format!(
+                "{} is not below {}, which was too high",
+                answer, high
+            ));
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let log = SubmissionLog {
+            submissions: vec![
+                Submission::new(Part::Two, Feedback::TooLow, "4105723140243"),
+                Submission::new(Part::Two, Feedback::Correct, "426214131924213"),
+            ],
+        };
+        let text: String = log.submissions.iter().map(|s| format!("{}\n", s)).collect();
+
+        assert!(!text.contains("426214131924213"));
+        assert_eq!(SubmissionLog::parse(&text).unwrap(), log);
+    }
+
+    #[test]
+    fn test_known_wrong() {
+        let log = SubmissionLog::parse(
+            "# part 2 attempts\npart2 too_low 100\npart2 too_high 200\npart2 wrong 150\n",
+        )
+        .unwrap();
+
+        let reason = |answer| log.known_wrong(Part::Two, answer).unwrap();
+
+        assert!(reason("150").contains("already rejected as wrong"));
+        assert!(reason("90").contains("which was too low"));
+        assert!(reason("250").contains("which was too high"));
+        assert_eq!(log.known_wrong(Part::Two, "149"), None);
+        assert_eq!(log.known_wrong(Part::One, "90"), None);
+    }
+}